@@ -0,0 +1,17 @@
+use std::env;
+
+use dotenvy::dotenv;
+
+use qweather_sdk::{blocking::QWeatherClient, client::ClientConfig};
+
+fn main() {
+    dotenv().expect(".env file not found");
+    env_logger::init();
+    let id = env::var("QWEATHER_ID").unwrap();
+    let key = env::var("QWEATHER_KEY").unwrap();
+    let client_config = ClientConfig::new(id, key);
+    let client = QWeatherClient::with_config(client_config);
+
+    let resp = client.weather_now("101010100").unwrap();
+    println!("{:#?}", resp);
+}
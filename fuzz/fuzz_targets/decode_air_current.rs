@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use qweather_sdk::api::air_quality::AirCurrentResponse;
+
+fuzz_target!(|data: &[u8]| {
+    qweather_sdk_fuzz::decode::<AirCurrentResponse>(data);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use qweather_sdk::api::air_quality::AirHourlyForecastResponse;
+
+fuzz_target!(|data: &[u8]| {
+    qweather_sdk_fuzz::decode::<AirHourlyForecastResponse>(data);
+});
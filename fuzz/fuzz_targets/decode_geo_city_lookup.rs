@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use qweather_sdk::api::geo::CityLookupResponse;
+
+fuzz_target!(|data: &[u8]| {
+    qweather_sdk_fuzz::decode::<CityLookupResponse>(data);
+});
@@ -0,0 +1,57 @@
+#![no_main]
+
+//! 使用任意状态码和响应体驱动 `request_api`，覆盖按 `code` 分发、非JSON错误响应和缓存的逻辑
+
+use std::{sync::OnceLock, time::Duration};
+
+use libfuzzer_sys::fuzz_target;
+use qweather_sdk::{
+    api::{weather::WeatherNowResponse, APIResponse, RawResponse},
+    cache::ResponseCache,
+    client::{ClientConfig, QWeatherClient},
+    transport::{MockTransport, Response},
+};
+use tokio::runtime::{Builder, Runtime};
+
+const STATUS: [u16; 10] = [200, 204, 400, 401, 402, 403, 404, 429, 500, 503];
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| Builder::new_current_thread().enable_all().build().unwrap())
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&status, body)) = data.split_first() else {
+        return;
+    };
+    let response = Response {
+        status: STATUS[status as usize % STATUS.len()],
+        headers: Default::default(),
+        body: body.to_vec(),
+    };
+
+    let transport = MockTransport::new();
+    transport.push_response(response.clone());
+    transport.push_response(response);
+    let mut config = ClientConfig::new("id", "key");
+    config.cache =
+        Some(ResponseCache::memory(16).with_ttl("/v7/weather/now", Duration::from_secs(60)));
+    let client = QWeatherClient::with_transport(config, transport);
+
+    runtime().block_on(async {
+        // 第二次请求可能命中缓存
+        let _ = client.weather_now("101010100").await;
+        if let Ok(APIResponse::Success(raw)) = client.weather_now_raw("101010100").await {
+            let _ = raw.decode::<WeatherNowResponse>();
+        }
+    });
+    if let Ok(body) = serde_json::from_slice(body) {
+        let raw = RawResponse {
+            status: 200,
+            headers: Default::default(),
+            body,
+        };
+        let _ = raw.code();
+        let _ = raw.decode::<serde_json::Value>();
+    }
+});
@@ -0,0 +1,8 @@
+//! 模糊测试共用的函数
+
+use serde::de::DeserializeOwned;
+
+/// 将任意字节解析为响应结构，只要求不 panic
+pub fn decode<T: DeserializeOwned>(data: &[u8]) {
+    let _ = serde_json::from_slice::<T>(data);
+}
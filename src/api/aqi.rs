@@ -0,0 +1,746 @@
+//! 本地AQI计算
+//!
+//! 根据污染物浓度离线计算空气质量指数，目前支持
+//!
+//! * 中国 [HJ 633-2012](https://www.mee.gov.cn/ywgz/fgbz/bz/bzwb/jcffbz/201203/t20120302_224166.shtml)（`cn-mee`）
+//! * 美国 EPA（`us-epa`），PM2.5 使用 2024 年修订前的分段，与和风天气返回值保持一致
+//!
+//! 计算结果为与接口返回值相同的 [`AQI`] 结构，可以与服务端数据混合使用。
+
+use crate::api::{
+    air_quality::{
+        AqiStandard, Concentration, ConcentrationUnit, Pollutant, PollutantCode, PrimaryPollutant,
+        SubIndex, AQI,
+    },
+    utils::RGBA,
+};
+
+/// 标准状况（25℃，101.325kPa）下理想气体的摩尔体积，单位 L/mol
+const MOLAR_VOLUME: f64 = 24.45;
+
+/// 参与AQI计算的污染物
+const POLLUTANTS: [PollutantCode; 6] = [
+    PollutantCode::Pm2p5,
+    PollutantCode::Pm10,
+    PollutantCode::No2,
+    PollutantCode::O3,
+    PollutantCode::So2,
+    PollutantCode::Co,
+];
+
+/// 浓度的平均时间
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AveragingPeriod {
+    /// 1小时平均
+    OneHour,
+    /// 8小时滑动平均
+    EightHours,
+}
+
+/// 污染物浓度读数
+#[derive(Debug, Clone)]
+pub struct PollutantReading {
+    /// [污染物](https://dev.qweather.com/docs/resource/air-info/#pollutants)的Code
+    pub code: PollutantCode,
+    /// 污染物的浓度值
+    pub concentration: Concentration,
+    /// 浓度的平均时间，目前只用于选择 O3 的浓度分段，为空时使用各标准的默认值
+    pub period: Option<AveragingPeriod>,
+}
+
+impl PollutantReading {
+    /// 创建新的浓度读数
+    pub fn new(
+        code: impl Into<PollutantCode>,
+        value: f64,
+        unit: impl Into<ConcentrationUnit>,
+    ) -> Self {
+        PollutantReading {
+            code: code.into(),
+            concentration: Concentration {
+                value,
+                unit: unit.into(),
+            },
+            period: None,
+        }
+    }
+
+    /// 设置浓度的平均时间
+    pub fn with_period(mut self, period: AveragingPeriod) -> Self {
+        self.period = Some(period);
+        self
+    }
+}
+
+impl From<&Pollutant> for PollutantReading {
+    fn from(pollutant: &Pollutant) -> Self {
+        PollutantReading {
+            code: pollutant.code.clone(),
+            concentration: pollutant.concentration.clone(),
+            period: None,
+        }
+    }
+}
+
+impl Concentration {
+    /// 将浓度值换算为其他单位
+    ///
+    /// 质量浓度（μg/m3、mg/m3）与体积浓度（ppb、ppm）之间按污染物的摩尔质量换算，
+    /// 颗粒物没有摩尔质量，只能在质量浓度单位之间换算。无法换算时返回 `None`。
+    ///
+    /// # Arguments
+    ///
+    /// * `pollutant`: 污染物的Code
+    /// * `unit`: 目标单位
+    pub fn convert_to(
+        &self,
+        pollutant: &PollutantCode,
+        unit: ConcentrationUnit,
+    ) -> Option<Concentration> {
+        let value = convert(pollutant, self.value, &self.unit, &unit)?;
+
+        Some(Concentration { value, unit })
+    }
+}
+
+/// 按指定标准计算空气质量指数
+///
+/// 目前仅支持 [`AqiStandard::CnMee`] 和 [`AqiStandard::UsEpa`]，其他标准返回 `None`。
+/// 无法识别的污染物或单位会被忽略，没有可用读数时返回 `None`。
+pub fn calculate(standard: AqiStandard, readings: &[PollutantReading]) -> Option<AQI> {
+    let levels = levels(&standard)?;
+
+    // 同一污染物有多个读数时取分指数最大的一个
+    let mut primary: Option<(&PollutantCode, f64)> = None;
+    for code in &POLLUTANTS {
+        let iaqi = readings
+            .iter()
+            .filter(|reading| reading.code == *code)
+            .filter_map(|reading| iaqi(&standard, reading))
+            .map(|iaqi| round(&standard, iaqi))
+            .fold(None, |max: Option<f64>, iaqi| {
+                Some(max.map_or(iaqi, |max| max.max(iaqi)))
+            });
+        if let Some(iaqi) = iaqi {
+            if primary.is_none_or(|(_, max)| iaqi > max) {
+                primary = Some((code, iaqi));
+            }
+        }
+    }
+    let (code, aqi) = primary?;
+
+    let &(level, _, category, (red, green, blue)) =
+        levels.iter().find(|(_, upper, _, _)| aqi <= *upper)?;
+
+    let primary_pollutant = if standard == AqiStandard::CnMee && aqi <= 50.0 {
+        None
+    } else {
+        Some(PrimaryPollutant {
+            code: code.clone(),
+            name: pollutant_name(code).to_string(),
+            full_name: pollutant_full_name(&standard, code).to_string(),
+        })
+    };
+
+    Some(AQI {
+        name: standard_name(&standard).to_string(),
+        code: standard,
+        aqi,
+        aqi_display: format!("{}", aqi),
+        level,
+        category: category.to_string(),
+        color: RGBA {
+            red,
+            green,
+            blue,
+            alpha: 1.0,
+        },
+        primary_pollutant,
+        health: None,
+    })
+}
+
+/// 按指定标准计算单个污染物的分指数
+///
+/// 不支持的标准、污染物或单位返回 `None`。
+pub fn sub_index(standard: AqiStandard, reading: &PollutantReading) -> Option<SubIndex> {
+    let aqi = round(&standard, iaqi(&standard, reading)?);
+
+    Some(SubIndex {
+        code: standard,
+        aqi,
+        aqi_display: format!("{}", aqi),
+    })
+}
+
+/// 按中国 HJ 633-2012 计算空气质量指数
+///
+/// SO2、NO2、CO 使用1小时平均浓度限值，PM2.5、PM10 使用24小时平均浓度限值，
+/// SO2 1小时浓度超过 800μg/m3 时改用24小时限值。O3 默认使用1小时平均浓度限值，
+/// 8小时滑动平均浓度（[`AveragingPeriod::EightHours`]）使用8小时限值；8小时浓度超过 800μg/m3 时
+/// 不计算其分指数，按同时传入的 O3 1小时浓度计算，没有1小时浓度时忽略该读数。分指数向上取整，AQI 大于 50 时
+/// 分指数最大的污染物为首要污染物。
+pub fn cn_mee(readings: &[PollutantReading]) -> Option<AQI> {
+    calculate(AqiStandard::CnMee, readings)
+}
+
+/// 按美国 EPA 标准计算空气质量指数
+///
+/// O3 默认使用8小时平均浓度分段，超过 0.200ppm 时分指数为 300；1小时平均浓度
+/// （[`AveragingPeriod::OneHour`]）使用1小时分段，低于 0.125ppm 时没有分指数。SO2 超过 304ppb 时
+/// 使用24小时分段。浓度按 EPA 规定截断后计算，分指数四舍五入，分指数最大的污染物为首要污染物。
+pub fn us_epa(readings: &[PollutantReading]) -> Option<AQI> {
+    calculate(AqiStandard::UsEpa, readings)
+}
+
+/// 按中国 HJ 633-2012 计算单个污染物的分指数
+pub fn cn_mee_sub_index(reading: &PollutantReading) -> Option<SubIndex> {
+    sub_index(AqiStandard::CnMee, reading)
+}
+
+/// 按美国 EPA 标准计算单个污染物的分指数
+pub fn us_epa_sub_index(reading: &PollutantReading) -> Option<SubIndex> {
+    sub_index(AqiStandard::UsEpa, reading)
+}
+
+/// 摩尔质量，单位 g/mol，颗粒物没有摩尔质量
+fn molar_mass(code: &PollutantCode) -> Option<f64> {
+    match code {
+        PollutantCode::No2 => Some(46.0055),
+        PollutantCode::O3 => Some(47.9982),
+        PollutantCode::So2 => Some(64.066),
+        PollutantCode::Co => Some(28.010),
+        _ => None,
+    }
+}
+
+/// 浓度单位换算
+fn convert(
+    code: &PollutantCode,
+    value: f64,
+    from: &ConcentrationUnit,
+    to: &ConcentrationUnit,
+) -> Option<f64> {
+    if from == to && !matches!(from, ConcentrationUnit::Unknown(_)) {
+        return Some(value);
+    }
+    let micrograms = match from {
+        ConcentrationUnit::MicrogramsPerCubicMeter => value,
+        ConcentrationUnit::MilligramsPerCubicMeter => value * 1000.0,
+        ConcentrationUnit::Ppb => value * molar_mass(code)? / MOLAR_VOLUME,
+        ConcentrationUnit::Ppm => value * 1000.0 * molar_mass(code)? / MOLAR_VOLUME,
+        ConcentrationUnit::Unknown(_) => return None,
+    };
+    let converted = match to {
+        ConcentrationUnit::MicrogramsPerCubicMeter => micrograms,
+        ConcentrationUnit::MilligramsPerCubicMeter => micrograms / 1000.0,
+        ConcentrationUnit::Ppb => micrograms * MOLAR_VOLUME / molar_mass(code)?,
+        ConcentrationUnit::Ppm => micrograms * MOLAR_VOLUME / molar_mass(code)? / 1000.0,
+        ConcentrationUnit::Unknown(_) => return None,
+    };
+
+    Some(converted)
+}
+
+/// 浓度分段 (浓度下限, 浓度上限, 分指数下限, 分指数上限)
+type Breakpoints = &'static [(f64, f64, f64, f64)];
+
+const CN_PM2P5_24H: Breakpoints = &[
+    (0.0, 35.0, 0.0, 50.0),
+    (35.0, 75.0, 50.0, 100.0),
+    (75.0, 115.0, 100.0, 150.0),
+    (115.0, 150.0, 150.0, 200.0),
+    (150.0, 250.0, 200.0, 300.0),
+    (250.0, 350.0, 300.0, 400.0),
+    (350.0, 500.0, 400.0, 500.0),
+];
+const CN_PM10_24H: Breakpoints = &[
+    (0.0, 50.0, 0.0, 50.0),
+    (50.0, 150.0, 50.0, 100.0),
+    (150.0, 250.0, 100.0, 150.0),
+    (250.0, 350.0, 150.0, 200.0),
+    (350.0, 420.0, 200.0, 300.0),
+    (420.0, 500.0, 300.0, 400.0),
+    (500.0, 600.0, 400.0, 500.0),
+];
+const CN_SO2_1H: Breakpoints = &[
+    (0.0, 150.0, 0.0, 50.0),
+    (150.0, 500.0, 50.0, 100.0),
+    (500.0, 650.0, 100.0, 150.0),
+    (650.0, 800.0, 150.0, 200.0),
+];
+const CN_SO2_24H: Breakpoints = &[
+    (0.0, 50.0, 0.0, 50.0),
+    (50.0, 150.0, 50.0, 100.0),
+    (150.0, 475.0, 100.0, 150.0),
+    (475.0, 800.0, 150.0, 200.0),
+    (800.0, 1600.0, 200.0, 300.0),
+    (1600.0, 2100.0, 300.0, 400.0),
+    (2100.0, 2620.0, 400.0, 500.0),
+];
+const CN_NO2_1H: Breakpoints = &[
+    (0.0, 100.0, 0.0, 50.0),
+    (100.0, 200.0, 50.0, 100.0),
+    (200.0, 700.0, 100.0, 150.0),
+    (700.0, 1200.0, 150.0, 200.0),
+    (1200.0, 2340.0, 200.0, 300.0),
+    (2340.0, 3090.0, 300.0, 400.0),
+    (3090.0, 3840.0, 400.0, 500.0),
+];
+const CN_CO_1H: Breakpoints = &[
+    (0.0, 5.0, 0.0, 50.0),
+    (5.0, 10.0, 50.0, 100.0),
+    (10.0, 35.0, 100.0, 150.0),
+    (35.0, 60.0, 150.0, 200.0),
+    (60.0, 90.0, 200.0, 300.0),
+    (90.0, 120.0, 300.0, 400.0),
+    (120.0, 150.0, 400.0, 500.0),
+];
+const CN_O3_1H: Breakpoints = &[
+    (0.0, 160.0, 0.0, 50.0),
+    (160.0, 200.0, 50.0, 100.0),
+    (200.0, 300.0, 100.0, 150.0),
+    (300.0, 400.0, 150.0, 200.0),
+    (400.0, 800.0, 200.0, 300.0),
+    (800.0, 1000.0, 300.0, 400.0),
+    (1000.0, 1200.0, 400.0, 500.0),
+];
+const CN_O3_8H: Breakpoints = &[
+    (0.0, 100.0, 0.0, 50.0),
+    (100.0, 160.0, 50.0, 100.0),
+    (160.0, 215.0, 100.0, 150.0),
+    (215.0, 265.0, 150.0, 200.0),
+    (265.0, 800.0, 200.0, 300.0),
+];
+
+const US_PM2P5: Breakpoints = &[
+    (0.0, 12.0, 0.0, 50.0),
+    (12.1, 35.4, 51.0, 100.0),
+    (35.5, 55.4, 101.0, 150.0),
+    (55.5, 150.4, 151.0, 200.0),
+    (150.5, 250.4, 201.0, 300.0),
+    (250.5, 350.4, 301.0, 400.0),
+    (350.5, 500.4, 401.0, 500.0),
+];
+const US_PM10: Breakpoints = &[
+    (0.0, 54.0, 0.0, 50.0),
+    (55.0, 154.0, 51.0, 100.0),
+    (155.0, 254.0, 101.0, 150.0),
+    (255.0, 354.0, 151.0, 200.0),
+    (355.0, 424.0, 201.0, 300.0),
+    (425.0, 504.0, 301.0, 400.0),
+    (505.0, 604.0, 401.0, 500.0),
+];
+const US_O3_8H: Breakpoints = &[
+    (0.0, 0.054, 0.0, 50.0),
+    (0.055, 0.070, 51.0, 100.0),
+    (0.071, 0.085, 101.0, 150.0),
+    (0.086, 0.105, 151.0, 200.0),
+    (0.106, 0.200, 201.0, 300.0),
+];
+/// 1小时 O3 只定义了 0.125ppm 以上的分段
+const US_O3_1H: Breakpoints = &[
+    (0.125, 0.164, 101.0, 150.0),
+    (0.165, 0.204, 151.0, 200.0),
+    (0.205, 0.404, 201.0, 300.0),
+    (0.405, 0.504, 301.0, 400.0),
+    (0.505, 0.604, 401.0, 500.0),
+];
+const US_CO: Breakpoints = &[
+    (0.0, 4.4, 0.0, 50.0),
+    (4.5, 9.4, 51.0, 100.0),
+    (9.5, 12.4, 101.0, 150.0),
+    (12.5, 15.4, 151.0, 200.0),
+    (15.5, 30.4, 201.0, 300.0),
+    (30.5, 40.4, 301.0, 400.0),
+    (40.5, 50.4, 401.0, 500.0),
+];
+const US_SO2: Breakpoints = &[
+    (0.0, 35.0, 0.0, 50.0),
+    (36.0, 75.0, 51.0, 100.0),
+    (76.0, 185.0, 101.0, 150.0),
+    (186.0, 304.0, 151.0, 200.0),
+    (305.0, 604.0, 201.0, 300.0),
+    (605.0, 804.0, 301.0, 400.0),
+    (805.0, 1004.0, 401.0, 500.0),
+];
+const US_NO2: Breakpoints = &[
+    (0.0, 53.0, 0.0, 50.0),
+    (54.0, 100.0, 51.0, 100.0),
+    (101.0, 360.0, 101.0, 150.0),
+    (361.0, 649.0, 151.0, 200.0),
+    (650.0, 1249.0, 201.0, 300.0),
+    (1250.0, 1649.0, 301.0, 400.0),
+    (1650.0, 2049.0, 401.0, 500.0),
+];
+
+/// 分段线性插值，浓度超出最高分段时取 500
+fn interpolate(breakpoints: Breakpoints, concentration: f64) -> Option<f64> {
+    if !concentration.is_finite() || concentration < 0.0 {
+        return None;
+    }
+    let (_, c_max, _, i_max) = *breakpoints.last()?;
+    if concentration > c_max {
+        return Some(i_max);
+    }
+    // 分段之间的空隙按下一分段计算
+    breakpoints
+        .iter()
+        .find(|(_, c_high, _, _)| concentration <= *c_high)
+        .map(|&(c_low, c_high, i_low, i_high)| {
+            let concentration = concentration.max(c_low);
+            (i_high - i_low) / (c_high - c_low) * (concentration - c_low) + i_low
+        })
+}
+
+/// 按精度截断浓度值
+fn truncate(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    // 加上极小值，避免 0.07 * 1000 之类的浮点误差被截断
+    (value * factor + 1e-9).floor() / factor
+}
+
+/// AQI 等级信息 (等级, 分指数上限, 类别, 颜色)
+type Levels = &'static [(i32, f64, &'static str, (u8, u8, u8))];
+
+const CN_LEVELS: Levels = &[
+    (1, 50.0, "优", (0, 228, 0)),
+    (2, 100.0, "良", (255, 255, 0)),
+    (3, 150.0, "轻度污染", (255, 126, 0)),
+    (4, 200.0, "中度污染", (255, 0, 0)),
+    (5, 300.0, "重度污染", (153, 0, 76)),
+    (6, f64::INFINITY, "严重污染", (126, 0, 35)),
+];
+
+const US_LEVELS: Levels = &[
+    (1, 50.0, "Good", (0, 228, 0)),
+    (2, 100.0, "Moderate", (255, 255, 0)),
+    (3, 150.0, "Unhealthy for Sensitive Groups", (255, 126, 0)),
+    (4, 200.0, "Unhealthy", (255, 0, 0)),
+    (5, 300.0, "Very Unhealthy", (143, 63, 151)),
+    (6, f64::INFINITY, "Hazardous", (126, 0, 35)),
+];
+
+fn standard_name(standard: &AqiStandard) -> &'static str {
+    match standard {
+        AqiStandard::CnMee => "AQI (CN)",
+        AqiStandard::UsEpa => "AQI (US)",
+        _ => "",
+    }
+}
+
+fn levels(standard: &AqiStandard) -> Option<Levels> {
+    match standard {
+        AqiStandard::CnMee => Some(CN_LEVELS),
+        AqiStandard::UsEpa => Some(US_LEVELS),
+        _ => None,
+    }
+}
+
+fn pollutant_name(code: &PollutantCode) -> &str {
+    match code {
+        PollutantCode::Pm2p5 => "PM 2.5",
+        PollutantCode::Pm10 => "PM 10",
+        PollutantCode::No2 => "NO2",
+        PollutantCode::O3 => "O3",
+        PollutantCode::So2 => "SO2",
+        PollutantCode::Co => "CO",
+        PollutantCode::Unknown(code) => code,
+    }
+}
+
+/// 污染物全称，中国标准使用中文，美国标准使用英文
+fn pollutant_full_name<'a>(standard: &AqiStandard, code: &'a PollutantCode) -> &'a str {
+    match (standard, code) {
+        (AqiStandard::CnMee, PollutantCode::Pm2p5) => "颗粒物（粒径小于等于2.5µm）",
+        (AqiStandard::CnMee, PollutantCode::Pm10) => "颗粒物（粒径小于等于10µm）",
+        (AqiStandard::CnMee, PollutantCode::No2) => "二氧化氮",
+        (AqiStandard::CnMee, PollutantCode::O3) => "臭氧",
+        (AqiStandard::CnMee, PollutantCode::So2) => "二氧化硫",
+        (AqiStandard::CnMee, PollutantCode::Co) => "一氧化碳",
+        (_, PollutantCode::Pm2p5) => "Fine particulate matter (<2.5µm)",
+        (_, PollutantCode::Pm10) => "Inhalable particulate matter (<10µm)",
+        (_, PollutantCode::No2) => "Nitrogen dioxide",
+        (_, PollutantCode::O3) => "Ozone",
+        (_, PollutantCode::So2) => "Sulfur dioxide",
+        (_, PollutantCode::Co) => "Carbon monoxide",
+        (_, PollutantCode::Unknown(code)) => code,
+    }
+}
+
+/// 计算单个污染物未取整的分指数
+fn iaqi(standard: &AqiStandard, reading: &PollutantReading) -> Option<f64> {
+    use ConcentrationUnit::{
+        MicrogramsPerCubicMeter as Ug, MilligramsPerCubicMeter as Mg, Ppb, Ppm,
+    };
+
+    let code = &reading.code;
+    let from = &reading.concentration.unit;
+    let value = reading.concentration.value;
+    let eight_hours = reading.period == Some(AveragingPeriod::EightHours);
+    match standard {
+        AqiStandard::CnMee => match code {
+            PollutantCode::Pm2p5 => interpolate(CN_PM2P5_24H, convert(code, value, from, &Ug)?),
+            PollutantCode::Pm10 => interpolate(CN_PM10_24H, convert(code, value, from, &Ug)?),
+            PollutantCode::No2 => interpolate(CN_NO2_1H, convert(code, value, from, &Ug)?),
+            PollutantCode::O3 if eight_hours => {
+                let value = convert(code, value, from, &Ug)?;
+                // 8小时浓度超过 800μg/m3 时不计算分指数，改用1小时浓度的分指数
+                if value > 800.0 {
+                    return None;
+                }
+                interpolate(CN_O3_8H, value)
+            }
+            PollutantCode::O3 => interpolate(CN_O3_1H, convert(code, value, from, &Ug)?),
+            PollutantCode::So2 => {
+                let value = convert(code, value, from, &Ug)?;
+                if value > 800.0 {
+                    interpolate(CN_SO2_24H, value)
+                } else {
+                    interpolate(CN_SO2_1H, value)
+                }
+            }
+            PollutantCode::Co => interpolate(CN_CO_1H, convert(code, value, from, &Mg)?),
+            PollutantCode::Unknown(_) => None,
+        },
+        AqiStandard::UsEpa => {
+            let (breakpoints, unit, decimals) = match code {
+                PollutantCode::Pm2p5 => (US_PM2P5, Ug, 1),
+                PollutantCode::Pm10 => (US_PM10, Ug, 0),
+                PollutantCode::O3 if reading.period == Some(AveragingPeriod::OneHour) => {
+                    (US_O3_1H, Ppm, 3)
+                }
+                PollutantCode::O3 => (US_O3_8H, Ppm, 3),
+                PollutantCode::Co => (US_CO, Ppm, 1),
+                PollutantCode::So2 => (US_SO2, Ppb, 0),
+                PollutantCode::No2 => (US_NO2, Ppb, 0),
+                PollutantCode::Unknown(_) => return None,
+            };
+            let value = truncate(convert(code, value, from, &unit)?, decimals);
+            // 1小时 O3 只定义了 0.125ppm 以上的分段，更低的浓度没有分指数
+            if value < breakpoints.first()?.0 {
+                return None;
+            }
+            interpolate(breakpoints, value)
+        }
+        _ => None,
+    }
+}
+
+/// 分指数取整
+fn round(standard: &AqiStandard, iaqi: f64) -> f64 {
+    match standard {
+        AqiStandard::CnMee => (iaqi - 1e-9).ceil().max(0.0),
+        _ => iaqi.round(),
+    }
+}
+
+#[test]
+fn test_convert_concentration() {
+    let no2 = Concentration {
+        value: 6.77,
+        unit: ConcentrationUnit::Ppb,
+    };
+    let ug = no2
+        .convert_to(
+            &PollutantCode::No2,
+            ConcentrationUnit::MicrogramsPerCubicMeter,
+        )
+        .unwrap();
+    assert!((ug.value - 12.738).abs() < 0.01);
+    let back = ug
+        .convert_to(&PollutantCode::No2, ConcentrationUnit::Ppb)
+        .unwrap();
+    assert!((back.value - 6.77).abs() < 1e-9);
+
+    let co = Concentration {
+        value: 0.4,
+        unit: ConcentrationUnit::MilligramsPerCubicMeter,
+    };
+    let ppm = co
+        .convert_to(&PollutantCode::Co, ConcentrationUnit::Ppm)
+        .unwrap();
+    assert!((ppm.value - 0.349).abs() < 0.001);
+
+    let pm2p5 = Concentration {
+        value: 12.0,
+        unit: ConcentrationUnit::MicrogramsPerCubicMeter,
+    };
+    assert!(pm2p5
+        .convert_to(&PollutantCode::Pm2p5, ConcentrationUnit::Ppb)
+        .is_none());
+    assert_eq!(
+        pm2p5
+            .convert_to(
+                &PollutantCode::Pm2p5,
+                ConcentrationUnit::MilligramsPerCubicMeter
+            )
+            .unwrap()
+            .value,
+        0.012
+    );
+}
+
+#[test]
+fn test_us_epa_matches_server() {
+    use crate::api::air_quality::AirCurrentResponse;
+
+    // 摘自实时空气质量接口返回值，O3 的单位标注有误（实际为 ppm），不参与比较
+    let json_data = crate::fixtures::body("air_current", "null_health");
+
+    let air_current: AirCurrentResponse = serde_json::from_str(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&air_current);
+    let pollutants = air_current.pollutants.as_ref().unwrap();
+
+    // 服务端使用未经舍入的浓度计算，分指数允许 1 的误差
+    for pollutant in pollutants {
+        let local = us_epa_sub_index(&pollutant.into()).unwrap();
+        let server = pollutant.sub_index(AqiStandard::UsEpa).unwrap();
+        assert_eq!(local.code, server.code);
+        assert!(
+            (local.aqi - server.aqi).abs() <= 1.0,
+            "{}: local {} server {}",
+            pollutant.code,
+            local.aqi,
+            server.aqi
+        );
+    }
+
+    let readings: Vec<PollutantReading> = pollutants.iter().map(Into::into).collect();
+    let local = us_epa(&readings).unwrap();
+    let server = air_current.index(AqiStandard::UsEpa).unwrap();
+    assert_eq!(local.code, server.code);
+    assert_eq!(local.name, server.name);
+    assert_eq!(local.aqi, server.aqi);
+    assert_eq!(local.aqi_display, server.aqi_display);
+    assert_eq!(local.level, server.level);
+    assert_eq!(local.category, server.category);
+    assert_eq!(local.color.red, server.color.red);
+    assert_eq!(local.color.green, server.color.green);
+    assert_eq!(local.color.blue, server.color.blue);
+    let local_primary = local.primary_pollutant.unwrap();
+    let server_primary = server.primary_pollutant.as_ref().unwrap();
+    assert_eq!(local_primary.code, server_primary.code);
+    assert_eq!(local_primary.name, server_primary.name);
+    assert_eq!(local_primary.full_name, server_primary.full_name);
+}
+
+#[test]
+fn test_cn_mee_matches_server() {
+    use crate::api::air_quality::AirCurrentResponse;
+
+    let json_data = crate::fixtures::body("air_current", "cn_mee");
+
+    let air_current: AirCurrentResponse = serde_json::from_str(json_data).unwrap();
+    let pollutants = air_current.pollutants.as_ref().unwrap();
+
+    for pollutant in pollutants {
+        let local = cn_mee_sub_index(&pollutant.into()).unwrap();
+        let server = pollutant.sub_index(AqiStandard::CnMee).unwrap();
+        assert_eq!(local.code, server.code);
+        assert_eq!(local.aqi, server.aqi, "{}", pollutant.code);
+        assert_eq!(local.aqi_display, server.aqi_display);
+    }
+
+    let readings: Vec<PollutantReading> = pollutants.iter().map(Into::into).collect();
+    let local = cn_mee(&readings).unwrap();
+    let server = air_current.index(AqiStandard::CnMee).unwrap();
+    assert_eq!(local.code, server.code);
+    assert_eq!(local.name, server.name);
+    assert_eq!(local.aqi, server.aqi);
+    assert_eq!(local.aqi_display, server.aqi_display);
+    assert_eq!(local.level, server.level);
+    assert_eq!(local.category, server.category);
+    assert_eq!(local.color.red, server.color.red);
+    assert_eq!(local.color.green, server.color.green);
+    assert_eq!(local.color.blue, server.color.blue);
+    let local_primary = local.primary_pollutant.unwrap();
+    let server_primary = server.primary_pollutant.as_ref().unwrap();
+    assert_eq!(local_primary.code, server_primary.code);
+    assert_eq!(local_primary.name, server_primary.name);
+    assert_eq!(local_primary.full_name, server_primary.full_name);
+}
+
+#[test]
+fn test_o3_averaging_period() {
+    let us = |ppm: f64, period: Option<AveragingPeriod>| {
+        let mut reading = PollutantReading::new("o3", ppm, "ppm");
+        reading.period = period;
+        us_epa_sub_index(&reading).map(|sub_index| sub_index.aqi)
+    };
+
+    // 8小时分段随浓度单调递增，超过 0.200ppm 时为 300
+    let mut previous = 0.0;
+    for ppm in (0..=300).map(|i| i as f64 * 0.001) {
+        let aqi = us(ppm, None).unwrap();
+        assert!(aqi >= previous, "{} ppm: {} < {}", ppm, aqi, previous);
+        previous = aqi;
+    }
+    assert_eq!(us(0.200, None), Some(300.0));
+    assert_eq!(us(0.205, None), Some(300.0));
+    assert_eq!(us(0.070, Some(AveragingPeriod::EightHours)), Some(100.0));
+
+    // 1小时分段从 0.125ppm 开始
+    let one_hour = Some(AveragingPeriod::OneHour);
+    assert_eq!(us(0.100, one_hour), None);
+    assert_eq!(us(0.125, one_hour), Some(101.0));
+    assert_eq!(us(0.205, one_hour), Some(201.0));
+    assert_eq!(us(0.505, one_hour), Some(401.0));
+
+    // 中国标准默认使用1小时限值
+    let eight_hours = Some(AveragingPeriod::EightHours);
+    let cn = |ug: f64, period: Option<AveragingPeriod>| {
+        let mut reading = PollutantReading::new("o3", ug, "μg/m3");
+        reading.period = period;
+        cn_mee_sub_index(&reading).map(|sub_index| sub_index.aqi)
+    };
+    assert_eq!(cn(160.0, None), Some(50.0));
+    assert_eq!(cn(160.0, eight_hours), Some(100.0));
+    assert_eq!(cn(800.0, eight_hours), Some(300.0));
+
+    // 8小时浓度超过 800μg/m3 时改用1小时浓度
+    assert_eq!(cn(900.0, eight_hours), None);
+    let o3_8h =
+        PollutantReading::new("o3", 900.0, "μg/m3").with_period(AveragingPeriod::EightHours);
+    let o3_1h = PollutantReading::new("o3", 900.0, "μg/m3").with_period(AveragingPeriod::OneHour);
+    // (400 - 300) / (1000 - 800) * (900 - 800) + 300 = 350
+    let aqi = cn_mee(&[o3_8h.clone(), o3_1h]).unwrap();
+    assert_eq!(aqi.aqi, 350.0);
+    assert_eq!(aqi.primary_pollutant.unwrap().code, PollutantCode::O3);
+    assert!(cn_mee(&[o3_8h]).is_none());
+}
+
+#[test]
+fn test_cn_mee() {
+    let readings = vec![
+        PollutantReading::new("pm2p5", 80.0, "μg/m3"),
+        PollutantReading::new("pm10", 120.0, "μg/m3"),
+        PollutantReading::new("no2", 20.0, "ppb"),
+        PollutantReading::new("co", 0.8, "mg/m3"),
+        PollutantReading::new("unknown", 1.0, "μg/m3"),
+    ];
+
+    // PM2.5: (150 - 100) / (115 - 75) * (80 - 75) + 100 = 106.25
+    let sub_index = cn_mee_sub_index(&readings[0]).unwrap();
+    assert_eq!(sub_index.aqi, 107.0);
+    assert_eq!(sub_index.aqi_display, "107");
+    let micro_sign = PollutantReading::new("pm2p5", 80.0, "\u{b5}g/m3");
+    assert_eq!(cn_mee_sub_index(&micro_sign).unwrap().aqi, 107.0);
+
+    let aqi = cn_mee(&readings).unwrap();
+    assert_eq!(aqi.code, "cn-mee");
+    assert_eq!(aqi.aqi, 107.0);
+    assert_eq!(aqi.level, 3);
+    assert_eq!(aqi.category, "轻度污染");
+    assert_eq!(aqi.color.red, 255);
+    assert_eq!(aqi.color.green, 126);
+    assert_eq!(aqi.primary_pollutant.unwrap().code, "pm2p5");
+
+    // AQI 不大于 50 时没有首要污染物
+    let clean = cn_mee(&[PollutantReading::new("pm2p5", 10.0, "μg/m3")]).unwrap();
+    assert_eq!(clean.level, 1);
+    assert_eq!(clean.category, "优");
+    assert!(clean.primary_pollutant.is_none());
+
+    assert!(cn_mee(&[PollutantReading::new("pm2p5", 10.0, "ppb")]).is_none());
+}
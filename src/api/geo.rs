@@ -2,7 +2,11 @@ use serde::{Deserialize, Serialize};
 use serde_aux::prelude::{deserialize_bool_from_anything, deserialize_number_from_string};
 use std::collections::BTreeMap;
 
-use crate::{api::Refer, client::QWeatherClient, APIResult, GEO_API_URL};
+use crate::{
+    api::{RawResponse, Refer},
+    client::QWeatherClient,
+    APIResult,
+};
 
 /// 城市搜索请求参数
 #[derive(Default)]
@@ -90,7 +94,18 @@ impl QWeatherClient {
         &self,
         city_look_up_input: CityLookupInput<'_>,
     ) -> APIResult<CityLookupResponse> {
-        let url = format!("{}/v2/city/lookup", GEO_API_URL);
+        Ok(self
+            .geo_city_lookup_raw(city_look_up_input)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::geo_city_lookup`] 的原始响应
+    pub async fn geo_city_lookup_raw(
+        &self,
+        city_look_up_input: CityLookupInput<'_>,
+    ) -> APIResult<RawResponse> {
+        let url = format!("{}/v2/city/lookup", self.get_geo_api_host());
 
         let mut params = BTreeMap::new();
         params.insert(
@@ -108,7 +123,7 @@ impl QWeatherClient {
             params.insert("number".to_string(), number.to_string());
         }
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 
     /// 热门城市查询
@@ -126,7 +141,19 @@ impl QWeatherClient {
         range: Option<&str>,
         number: Option<i32>,
     ) -> APIResult<TopCityResponse> {
-        let url = format!("{}/v2/city/top", GEO_API_URL);
+        Ok(self
+            .geo_city_top_raw(range, number)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::geo_city_top`] 的原始响应
+    pub async fn geo_city_top_raw(
+        &self,
+        range: Option<&str>,
+        number: Option<i32>,
+    ) -> APIResult<RawResponse> {
+        let url = format!("{}/v2/city/top", self.get_geo_api_host());
 
         let mut params = BTreeMap::new();
 
@@ -137,7 +164,7 @@ impl QWeatherClient {
             params.insert("number".to_string(), number.to_string());
         }
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 
     /// POI搜索
@@ -163,7 +190,18 @@ impl QWeatherClient {
         &self,
         geo_poi_lookup_input: GeoPoiLookupInput<'_>,
     ) -> APIResult<POIResponse> {
-        let url = format!("{}/v2/poi/lookup", GEO_API_URL);
+        Ok(self
+            .geo_poi_lookup_raw(geo_poi_lookup_input)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::geo_poi_lookup`] 的原始响应
+    pub async fn geo_poi_lookup_raw(
+        &self,
+        geo_poi_lookup_input: GeoPoiLookupInput<'_>,
+    ) -> APIResult<RawResponse> {
+        let url = format!("{}/v2/poi/lookup", self.get_geo_api_host());
 
         let mut params = BTreeMap::new();
         params.insert(
@@ -178,7 +216,7 @@ impl QWeatherClient {
             params.insert("number".to_string(), number.to_string());
         }
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 
     /// POI范围搜索
@@ -203,7 +241,18 @@ impl QWeatherClient {
         &self,
         geo_poi_range_input: GeoPoiRangeInput<'_>,
     ) -> APIResult<POIResponse> {
-        let url = format!("{}/v2/poi/range", GEO_API_URL);
+        Ok(self
+            .geo_poi_range_raw(geo_poi_range_input)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::geo_poi_range`] 的原始响应
+    pub async fn geo_poi_range_raw(
+        &self,
+        geo_poi_range_input: GeoPoiRangeInput<'_>,
+    ) -> APIResult<RawResponse> {
+        let url = format!("{}/v2/poi/range", self.get_geo_api_host());
 
         let mut params = BTreeMap::new();
         params.insert(
@@ -218,12 +267,12 @@ impl QWeatherClient {
             params.insert("number".to_string(), number.to_string());
         }
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 }
 
 /// 地点信息
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Location {
     /// 地区/城市名称
@@ -259,364 +308,70 @@ pub struct Location {
 }
 
 /// 城市搜索返回值
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct CityLookupResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     pub location: Vec<Location>,
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 热门城市查询返回值
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TopCityResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     pub top_city_list: Vec<Location>,
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// POI（兴趣点）
 pub type POI = Location;
 
 /// POI搜索返回值
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct POIResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     pub poi: Vec<POI>,
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[test]
 fn test_location() {
-    let json_data = r#"{
-  "code":"200",
-  "location":[
-    {
-      "name":"北京",
-      "id":"101010100",
-      "lat":"39.90499",
-      "lon":"116.40529",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"10",
-      "fxLink":"https://www.qweather.com/weather/beijing-101010100.html"
-    },
-    {
-      "name":"海淀",
-      "id":"101010200",
-      "lat":"39.95607",
-      "lon":"116.31032",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"15",
-      "fxLink":"https://www.qweather.com/weather/haidian-101010200.html"
-    },
-    {
-      "name":"朝阳",
-      "id":"101010300",
-      "lat":"39.92149",
-      "lon":"116.48641",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"15",
-      "fxLink":"https://www.qweather.com/weather/chaoyang-101010300.html"
-    },
-    {
-      "name":"昌平",
-      "id":"101010700",
-      "lat":"40.21809",
-      "lon":"116.23591",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"23",
-      "fxLink":"https://www.qweather.com/weather/changping-101010700.html"
-    },
-    {
-      "name":"房山",
-      "id":"101011200",
-      "lat":"39.73554",
-      "lon":"116.13916",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"23",
-      "fxLink":"https://www.qweather.com/weather/fangshan-101011200.html"
-    },
-    {
-      "name":"通州",
-      "id":"101010600",
-      "lat":"39.90249",
-      "lon":"116.65860",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"23",
-      "fxLink":"https://www.qweather.com/weather/tongzhou-101010600.html"
-    },
-    {
-      "name":"丰台",
-      "id":"101010900",
-      "lat":"39.86364",
-      "lon":"116.28696",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"25",
-      "fxLink":"https://www.qweather.com/weather/fengtai-101010900.html"
-    },
-    {
-      "name":"大兴",
-      "id":"101011100",
-      "lat":"39.72891",
-      "lon":"116.33804",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"25",
-      "fxLink":"https://www.qweather.com/weather/daxing-101011100.html"
-    },
-    {
-      "name":"延庆",
-      "id":"101010800",
-      "lat":"40.46532",
-      "lon":"115.98501",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"33",
-      "fxLink":"https://www.qweather.com/weather/yanqing-101010800.html"
-    },
-    {
-      "name":"平谷",
-      "id":"101011500",
-      "lat":"40.14478",
-      "lon":"117.11234",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"33",
-      "fxLink":"https://www.qweather.com/weather/pinggu-101011500.html"
-    }
-  ],
-  "refer":{
-    "sources":[
-      "QWeather"
-    ],
-    "license":[
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("geo_city_lookup", "success");
 
     let resp = serde_json::from_str::<CityLookupResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
 }
 
 #[test]
 fn test_city_top() {
-    let json_data = r#"{
-  "code":"200",
-  "topCityList":[
-    {
-      "name":"北京",
-      "id":"101010100",
-      "lat":"39.90499",
-      "lon":"116.40529",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"10",
-      "fxLink":"https://www.qweather.com/weather/beijing-101010100.html"
-    },
-    {
-      "name":"朝阳",
-      "id":"101010300",
-      "lat":"39.92149",
-      "lon":"116.48641",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"15",
-      "fxLink":"https://www.qweather.com/weather/chaoyang-101010300.html"
-    },
-    {
-      "name":"海淀",
-      "id":"101010200",
-      "lat":"39.95607",
-      "lon":"116.31032",
-      "adm2":"北京",
-      "adm1":"北京市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"15",
-      "fxLink":"https://www.qweather.com/weather/haidian-101010200.html"
-    },
-    {
-      "name":"深圳",
-      "id":"101280601",
-      "lat":"22.54700",
-      "lon":"114.08595",
-      "adm2":"深圳",
-      "adm1":"广东省",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"13",
-      "fxLink":"https://www.qweather.com/weather/shenzhen-101280601.html"
-    },
-    {
-      "name":"上海",
-      "id":"101020100",
-      "lat":"31.23171",
-      "lon":"121.47264",
-      "adm2":"上海",
-      "adm1":"上海市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"11",
-      "fxLink":"https://www.qweather.com/weather/shanghai-101020100.html"
-    },
-    {
-      "name":"浦东新区",
-      "id":"101020600",
-      "lat":"31.24594",
-      "lon":"121.56770",
-      "adm2":"上海",
-      "adm1":"上海市",
-      "country":"中国",
-      "tz":"Asia/Shanghai",
-      "utcOffset":"+08:00",
-      "isDst":"0",
-      "type":"city",
-      "rank":"15",
-      "fxLink":"https://www.qweather.com/weather/pudong-101020600.html"
-    }
-  ],
-  "refer":{
-    "sources":[
-      "QWeather"
-    ],
-    "license":[
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("geo_city_top", "success");
 
     let resp = serde_json::from_str::<TopCityResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
 }
 
 #[test]
 fn test_poi_lookup() {
-    let json_data = r#"{
-  "code": "200",
-  "poi": [
-    {
-      "name": "景山公园",
-      "id": "10101010012A",
-      "lat": "39.91999",
-      "lon": "116.38999",
-      "adm2": "北京",
-      "adm1": "北京",
-      "country": "中国",
-      "tz": "Asia/Shanghai",
-      "utcOffset": "+08:00",
-      "isDst": "0",
-      "type": "scenic",
-      "rank": "67",
-      "fxLink": "https://www.qweather.com"
-    },
-    {
-      "name": "静思园",
-      "id": "10119040702A",
-      "lat": "31.15999",
-      "lon": "120.68000",
-      "adm2": "苏州",
-      "adm1": "苏州",
-      "country": "中国",
-      "tz": "Asia/Shanghai",
-      "utcOffset": "+08:00",
-      "isDst": "0",
-      "type": "scenic",
-      "rank": "86",
-      "fxLink": "https://www.qweather.com"
-    }
-  ],
-  "refer": {
-    "sources": [
-      "QWeather"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("geo_poi_lookup", "success");
 
     let resp = serde_json::from_str::<POIResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
 }
@@ -4,7 +4,7 @@ use serde_aux::prelude::deserialize_number_from_string;
 use std::collections::BTreeMap;
 
 use crate::{
-    api::{decode_datetime, Refer},
+    api::{decode_datetime, encode_datetime, RawResponse, Refer},
     client::QWeatherClient,
     APIResult,
 };
@@ -29,18 +29,31 @@ impl QWeatherClient {
         type_: &str,
         day: i32,
     ) -> APIResult<IndicesForecastResponse> {
+        Ok(self
+            .indices_forecast_raw(location, type_, day)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::indices_forecast`] 的原始响应
+    pub async fn indices_forecast_raw(
+        &self,
+        location: &str,
+        type_: &str,
+        day: i32,
+    ) -> APIResult<RawResponse> {
         let url = format!("{}/v7/indices/{}d", self.get_api_host(), day);
 
         let mut params = BTreeMap::new();
         params.insert("location".to_string(), location.to_string());
         params.insert("type".to_string(), type_.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 }
 
 /// 天气指数预报
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DailyIndices {
     /// 预报日期
@@ -61,13 +74,16 @@ pub struct DailyIndices {
 }
 
 /// 天气指数预报返回数据
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct IndicesForecastResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     /// 当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前数据的响应式页面，便于嵌入网站或应用
     pub fx_link: String,
@@ -75,43 +91,17 @@ pub struct IndicesForecastResponse {
     pub daily: Vec<DailyIndices>,
     /// 数据来源
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[test]
 fn test_indices_forecast() {
-    let json_dat = r#"{
-  "code": "200",
-  "updateTime": "2021-12-16T18:35+08:00",
-  "fxLink": "http://hfx.link/2ax2",
-  "daily": [
-    {
-      "date": "2021-12-16",
-      "type": "1",
-      "name": "运动指数",
-      "level": "3",
-      "category": "较不宜",
-      "text": "天气较好，但考虑天气寒冷，风力较强，推荐您进行室内运动，若户外运动请注意保暖并做好准备活动。"
-    },
-    {
-      "date": "2021-12-16",
-      "type": "2",
-      "name": "洗车指数",
-      "level": "3",
-      "category": "较不宜",
-      "text": "较不宜洗车，未来一天无雨，风力较大，如果执意擦洗汽车，要做好蒙上污垢的心理准备。"
-    }
-  ],
-  "refer": {
-    "sources": [
-      "QWeather"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_dat = crate::fixtures::body("indices_forecast", "success");
 
     let resp: IndicesForecastResponse = serde_json::from_str(json_dat).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
     assert_eq!(resp.daily.len(), 2);
     assert_eq!(resp.daily[0].name, "运动指数");
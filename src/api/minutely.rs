@@ -4,7 +4,7 @@ use serde_aux::prelude::deserialize_number_from_string;
 use std::collections::BTreeMap;
 
 use crate::{
-    api::{decode_datetime, Refer},
+    api::{decode_datetime, encode_datetime, RawResponse, Refer},
     client::QWeatherClient,
     APIResult,
 };
@@ -23,20 +23,31 @@ impl QWeatherClient {
         &self,
         location: &str,
     ) -> APIResult<MinutePrecipitationResponse> {
+        Ok(self
+            .minutely_precipitation_raw(location)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::minutely_precipitation`] 的原始响应
+    pub async fn minutely_precipitation_raw(&self, location: &str) -> APIResult<RawResponse> {
         let url = format!("{}/v7/minutely/5m", self.get_api_host());
         let mut params = BTreeMap::new();
         params.insert("location".to_string(), location.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 }
 
 /// 分钟级降水
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Minutely {
     /// 预报时间
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub fx_time: DateTime<FixedOffset>,
     /// 5分钟累计降水量，单位毫米
     #[serde(deserialize_with = "deserialize_number_from_string")]
@@ -46,13 +57,16 @@ pub struct Minutely {
     pub type_: String,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct MinutePrecipitationResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     ///  当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前数据的响应式页面，便于嵌入网站或应用
     pub fx_link: String,
@@ -60,147 +74,16 @@ pub struct MinutePrecipitationResponse {
     pub summary: String,
     pub minutely: Vec<Minutely>,
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[test]
 fn test_minutely() {
-    let json_data = r#"{
-  "code": "200",
-  "updateTime": "2021-12-16T18:55+08:00",
-  "fxLink": "https://www.qweather.com",
-  "summary": "95分钟后雨就停了",
-  "minutely": [
-    {
-      "fxTime": "2021-12-16T18:55+08:00",
-      "precip": "0.15",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:00+08:00",
-      "precip": "0.23",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:05+08:00",
-      "precip": "0.21",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:10+08:00",
-      "precip": "0.17",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:15+08:00",
-      "precip": "0.18",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:20+08:00",
-      "precip": "0.24",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:25+08:00",
-      "precip": "0.31",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:30+08:00",
-      "precip": "0.37",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:35+08:00",
-      "precip": "0.41",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:40+08:00",
-      "precip": "0.43",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:45+08:00",
-      "precip": "0.41",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:50+08:00",
-      "precip": "0.36",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T19:55+08:00",
-      "precip": "0.32",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:00+08:00",
-      "precip": "0.27",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:05+08:00",
-      "precip": "0.22",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:10+08:00",
-      "precip": "0.17",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:15+08:00",
-      "precip": "0.11",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:20+08:00",
-      "precip": "0.06",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:25+08:00",
-      "precip": "0.0",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:30+08:00",
-      "precip": "0.0",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:35+08:00",
-      "precip": "0.0",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:40+08:00",
-      "precip": "0.0",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:45+08:00",
-      "precip": "0.0",
-      "type": "rain"
-    },
-    {
-      "fxTime": "2021-12-16T20:50+08:00",
-      "precip": "0.0",
-      "type": "rain"
-    }
-  ],
-  "refer": {
-    "sources": [
-      "QWeather"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("minutely_precipitation", "success");
 
     let resp = serde_json::from_str::<MinutePrecipitationResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
 }
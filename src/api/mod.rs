@@ -1,10 +1,18 @@
-use std::{fmt::Display, str::FromStr};
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
 
-use chrono::{DateTime, FixedOffset, Utc};
-use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+#[cfg(not(feature = "tracing"))]
+use log::error;
+use serde::{
+    de::{DeserializeOwned, Error},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use serde_json::Value;
+#[cfg(feature = "tracing")]
+use tracing::error;
 
 pub mod air_quality;
+pub mod aqi;
 pub mod geo;
 pub mod grid_weather;
 pub mod indices;
@@ -14,63 +22,175 @@ pub mod utils;
 pub mod warning;
 pub mod weather;
 
+/// 仅限订阅用户的接口路径，使用多密钥池时这些接口只会使用订阅密钥
+pub(crate) const SUBSCRIPTION_ONLY_PATHS: &[&str] = &[
+    "/v7/tropical/storm-forecast",
+    "/v7/tropical/storm-list",
+    "/v7/tropical/storm-track",
+];
+
+/// 解析接口返回的时间
+///
+/// 支持和风天气精确到分钟的格式（例如 `2024-06-05T10:00+08:00`、`2024-06-05T02:00Z`）和完整的
+/// RFC 3339 格式，时区可以是 `Z`、`+08:00` 或 `+0800`。
+pub fn parse_datetime(s: &str) -> Result<DateTime<FixedOffset>, chrono::ParseError> {
+    let s = s.trim();
+    DateTime::parse_from_rfc3339(s).or_else(|e| {
+        let minutes = match s.strip_suffix('Z').or_else(|| s.strip_suffix('z')) {
+            Some(local) => DateTime::parse_from_str(&format!("{}+0000", local), "%Y-%m-%dT%H:%M%z"),
+            None => DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M%z"),
+        };
+        // 两种格式都无法解析时返回 RFC 3339 的错误
+        minutes.map_err(|_| e)
+    })
+}
+
+/// 解析时间字符串，参见 [`parse_datetime`]
+fn deserialize_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    if s.trim().is_empty() {
+        return Ok(None);
+    }
+
+    parse_datetime(&s)
+        .map(Some)
+        .map_err(|e| D::Error::custom(format!("invalid datetime {:?}: {}", s, e)))
+}
+
+/// 反序列化时间，格式参见 [`parse_datetime`]
 pub fn decode_datetime<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-    println!("s = {:?}", s);
-    let dt = DateTime::<FixedOffset>::parse_from_str(&s, "%Y-%m-%dT%H:%M%z").unwrap();
-    println!("dt = {:?}", dt);
-    Ok(dt)
+    deserialize_datetime(deserializer)?.ok_or_else(|| D::Error::custom("empty datetime"))
 }
 
+/// 反序列化ISO 8601时间并转换为UTC，格式参见 [`parse_datetime`]
 pub fn decode_iso6801<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let iso8601_str = String::deserialize(deserializer)?;
-    let complete_date_str = if iso8601_str.ends_with('Z') {
-        format!("{}:00Z", &iso8601_str[..iso8601_str.len() - 1])
-    } else {
-        iso8601_str.to_string()
-    };
-
-    match DateTime::parse_from_rfc3339(&complete_date_str) {
-        Ok(datetime) => {
-            let datetime_utc = datetime.with_timezone(&Utc);
-            Ok(datetime_utc)
-        }
-        Err(e) => {
-            eprintln!("Failed to parse ISO 8601 string: {}", e);
-            Err(D::Error::custom(e.to_string()))
-        }
-    }
+    Ok(decode_datetime(deserializer)?.with_timezone(&Utc))
 }
 
+/// 反序列化可能为空的时间，空字符串或 `null` 返回 `None`，格式参见 [`parse_datetime`]
 pub fn option_decode_datetime<'de, D>(
     deserializer: D,
 ) -> Result<Option<DateTime<FixedOffset>>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s = String::deserialize(deserializer)?;
-    if s.is_empty() {
-        Ok(None)
+    deserialize_datetime(deserializer)
+}
+
+/// 是否精确到分钟
+fn is_whole_minute<Tz: chrono::TimeZone>(dt: &DateTime<Tz>) -> bool {
+    dt.timestamp() % 60 == 0 && dt.timestamp_subsec_nanos() == 0
+}
+
+/// 以 [`decode_datetime`] 接受的格式序列化，不含秒时为 `2024-06-05T10:00+08:00`
+pub fn encode_datetime<S>(dt: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if is_whole_minute(dt) {
+        serializer.collect_str(&dt.format("%Y-%m-%dT%H:%M%:z"))
     } else {
-        let dt = DateTime::<FixedOffset>::parse_from_str(&s, "%Y-%m-%dT%H:%M%z").unwrap();
-        Ok(Some(dt))
+        serializer.collect_str(&dt.to_rfc3339_opts(SecondsFormat::AutoSi, false))
     }
 }
+
+/// 以 [`decode_iso6801`] 接受的格式序列化，不含秒时为 `2024-06-05T02:00Z`
+pub fn encode_iso6801<S>(dt: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if is_whole_minute(dt) {
+        serializer.collect_str(&dt.format("%Y-%m-%dT%H:%MZ"))
+    } else {
+        serializer.collect_str(&dt.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+    }
+}
+
+/// 以 [`option_decode_datetime`] 接受的格式序列化，`None` 序列化为空字符串
+pub fn option_encode_datetime<S>(
+    dt: &Option<DateTime<FixedOffset>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match dt {
+        Some(dt) => encode_datetime(dt, serializer),
+        None => serializer.serialize_str(""),
+    }
+}
+
 /// API响应
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum APIResponse<T> {
     Success(T),
     Error(String),
+    /// 状态码为成功，但响应体无法解析为对应的数据结构
+    ParseError {
+        /// 解析错误
+        error: String,
+        /// 原始响应体
+        body: Value,
+    },
+}
+
+impl<T> APIResponse<T> {
+    /// 成功时使用 `f` 转换结果
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> APIResponse<U>) -> APIResponse<U> {
+        match self {
+            APIResponse::Success(value) => f(value),
+            APIResponse::Error(code) => APIResponse::Error(code),
+            APIResponse::ParseError { error, body } => APIResponse::ParseError { error, body },
+        }
+    }
+}
+
+/// 原始响应，命中缓存时 `headers` 为空
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawResponse {
+    /// HTTP状态码
+    pub status: u16,
+    /// 响应头，名称均为小写
+    pub headers: BTreeMap<String, String>,
+    /// 响应体
+    pub body: Value,
+}
+
+impl RawResponse {
+    /// 响应体中的[状态码](https://dev.qweather.com/docs/resource/status-code/)，新版接口成功时没有该字段
+    pub fn code(&self) -> Option<&str> {
+        self.body.get("code")?.as_str()
+    }
+
+    /// 按状态码解析为对应的数据结构
+    pub fn decode<T: DeserializeOwned>(self) -> APIResponse<T> {
+        match self.code() {
+            Some("200") | None => match T::deserialize(&self.body) {
+                Ok(value) => APIResponse::Success(value),
+                Err(e) => {
+                    error!("Failed to parse response: {}", e);
+                    APIResponse::ParseError {
+                        error: e.to_string(),
+                        body: self.body,
+                    }
+                }
+            },
+            Some(code) => APIResponse::Error(code.to_string()),
+        }
+    }
 }
 
 /// 数据来源
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct Refer {
     /// 原始数据来源，或数据源说明，可能为空
     pub sources: Vec<String>,
@@ -111,3 +231,157 @@ where
         },
     }
 }
+
+#[test]
+fn test_encode_datetime() {
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct Times {
+        #[serde(
+            deserialize_with = "decode_datetime",
+            serialize_with = "encode_datetime"
+        )]
+        local: DateTime<FixedOffset>,
+        #[serde(deserialize_with = "decode_iso6801", serialize_with = "encode_iso6801")]
+        utc: DateTime<Utc>,
+        #[serde(
+            deserialize_with = "option_decode_datetime",
+            serialize_with = "option_encode_datetime"
+        )]
+        empty: Option<DateTime<FixedOffset>>,
+    }
+
+    let json = serde_json::json!({
+        "local": "2024-06-05T10:00+08:00",
+        "utc": "2024-06-05T02:00Z",
+        "empty": "",
+    });
+    let times: Times = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&times).unwrap(), json);
+
+    let times = Times {
+        utc: times.utc + chrono::Duration::seconds(30),
+        ..times
+    };
+    assert_eq!(
+        serde_json::to_value(&times).unwrap()["utc"],
+        "2024-06-05T02:00:30+00:00"
+    );
+    crate::fixtures::assert_round_trip(&times);
+}
+
+#[test]
+fn test_decode_datetime() {
+    #[derive(Deserialize, Debug)]
+    struct Times {
+        #[serde(deserialize_with = "decode_datetime")]
+        local: DateTime<FixedOffset>,
+        #[serde(deserialize_with = "decode_iso6801")]
+        utc: DateTime<Utc>,
+        #[serde(deserialize_with = "option_decode_datetime")]
+        optional: Option<DateTime<FixedOffset>>,
+    }
+    let decode = |local: &str, utc: &str, optional: Value| {
+        serde_json::from_value::<Times>(serde_json::json!({
+            "local": local,
+            "utc": utc,
+            "optional": optional,
+        }))
+    };
+
+    let expected = DateTime::parse_from_rfc3339("2024-06-05T10:00:00+08:00").unwrap();
+    for local in [
+        "2024-06-05T10:00+08:00",
+        "2024-06-05T10:00+0800",
+        "2024-06-05T02:00Z",
+        "2024-06-05T10:00:00+08:00",
+        "2024-06-05T02:00:00.000Z",
+        " 2024-06-05T10:00+08:00 ",
+    ] {
+        let times = decode(local, local, Value::from(local)).unwrap();
+        assert_eq!(times.local, expected, "{}", local);
+        assert_eq!(times.utc, expected, "{}", local);
+        assert_eq!(times.optional, Some(expected), "{}", local);
+    }
+    let times = decode(
+        "2024-06-05T10:00+08:00",
+        "2024-06-05T02:00Z",
+        Value::from(""),
+    )
+    .unwrap();
+    assert_eq!(times.optional, None);
+    assert_eq!(
+        decode("2024-06-05T10:00+08:00", "2024-06-05T02:00Z", Value::Null)
+            .unwrap()
+            .optional,
+        None
+    );
+
+    for invalid in [
+        "",
+        "2024-06-05",
+        "2024-06-05T25:00+08:00",
+        "now",
+        "2024-06-05T10:00",
+    ] {
+        let e = decode(invalid, "2024-06-05T02:00Z", Value::Null).unwrap_err();
+        assert!(e.to_string().contains("datetime"), "{}", e);
+    }
+    assert!(decode("2024-06-05T10:00+08:00", "", Value::Null).is_err());
+    assert!(decode(
+        "2024-06-05T10:00+08:00",
+        "2024-06-05T02:00Z",
+        Value::from("x")
+    )
+    .is_err());
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn proptest_parse_datetime(
+        // 1900-01-01 至 2199-12-31
+        timestamp in -2_208_988_800i64..7_258_118_400,
+        // -12:00 至 +14:00，以15分钟为单位
+        quarters in -48i32..=56,
+        seconds in proptest::bool::ANY,
+    ) {
+        let offset = FixedOffset::east_opt(quarters * 15 * 60).unwrap();
+        let timestamp = if seconds { timestamp } else { timestamp - timestamp.rem_euclid(60) };
+        let dt = DateTime::from_timestamp(timestamp, 0).unwrap().with_timezone(&offset);
+
+        let mut formats = vec![
+            dt.to_rfc3339(),
+            dt.to_rfc3339_opts(SecondsFormat::Millis, true),
+            dt.with_timezone(&Utc).to_rfc3339_opts(SecondsFormat::Secs, true),
+        ];
+        if !seconds {
+            formats.push(dt.format("%Y-%m-%dT%H:%M%:z").to_string());
+            formats.push(dt.format("%Y-%m-%dT%H:%M%z").to_string());
+            formats.push(dt.with_timezone(&Utc).format("%Y-%m-%dT%H:%MZ").to_string());
+        }
+        for s in formats {
+            let parsed = parse_datetime(&s).unwrap();
+            proptest::prop_assert_eq!(parsed, dt, "{}", s);
+        }
+        // 序列化后可以重新解析
+        let value = serde_json::to_value(EncodedDatetime(dt)).unwrap();
+        let decoded: EncodedDatetime = serde_json::from_value(value).unwrap();
+        proptest::prop_assert_eq!(decoded.0, dt);
+    }
+
+    #[test]
+    fn proptest_decode_datetime_never_panics(s in "\\PC*") {
+        let _ = serde_json::from_value::<EncodedDatetime>(Value::String(s.clone()));
+        let _ = parse_datetime(&s);
+    }
+}
+
+#[cfg(test)]
+#[derive(Deserialize, Serialize)]
+struct EncodedDatetime(
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
+    DateTime<FixedOffset>,
+);
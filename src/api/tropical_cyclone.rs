@@ -4,7 +4,10 @@ use serde_aux::prelude::*;
 use std::collections::BTreeMap;
 
 use crate::{
-    api::{decode_datetime, deserialize_option_number_from_empty_string, Refer},
+    api::{
+        decode_datetime, deserialize_option_number_from_empty_string, encode_datetime, RawResponse,
+        Refer,
+    },
     client::QWeatherClient,
     APIResult,
 };
@@ -18,22 +21,36 @@ impl QWeatherClient {
     ///
     /// * storm_id : 需要查询的台风ID，StormID可通过台风查询API获取。例如 stormid=NP2018
     pub async fn storm_forecast(&self, storm_id: &str) -> APIResult<StormForecastResponse> {
-        let url = "https://api.qweather.com/v7/tropical/storm-forecast".to_string();
+        Ok(self
+            .storm_forecast_raw(storm_id)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::storm_forecast`] 的原始响应
+    pub async fn storm_forecast_raw(&self, storm_id: &str) -> APIResult<RawResponse> {
+        let url = format!(
+            "{}/v7/tropical/storm-forecast",
+            self.get_subscription_api_host()
+        );
         let mut params = BTreeMap::new();
         params.insert("stormid".to_string(), storm_id.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 }
 
 /// 台风预报返回值
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct StormForecastResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     /// 当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前数据的响应式页面，便于嵌入网站或应用
     pub fx_link: String,
@@ -41,14 +58,20 @@ pub struct StormForecastResponse {
     pub forecast: Vec<StormForecast>,
     /// 数据来源
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 台风预报
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct StormForecast {
     /// 台风预报时间
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub fx_time: DateTime<FixedOffset>,
     /// 台风所处纬度
     #[serde(deserialize_with = "deserialize_number_from_string")]
@@ -75,100 +98,10 @@ pub struct StormForecast {
 
 #[test]
 fn test_store_forecast() {
-    let json_data = r#"{
-  "code": "200",
-  "updateTime": "2021-07-27T03:00+00:00",
-  "fxLink": "https://www.qweather.com",
-  "forecast": [
-    {
-      "fxTime": "2021-07-27T20:00+08:00",
-      "lat": "31.7",
-      "lon": "118.4",
-      "type": "TS",
-      "pressure": "990",
-      "windSpeed": "18",
-      "moveSpeed": "",
-      "moveDir": "",
-      "move360": ""
-    },
-    {
-      "fxTime": "2021-07-28T08:00+08:00",
-      "lat": "32.5",
-      "lon": "117.4",
-      "type": "TD",
-      "pressure": "992",
-      "windSpeed": "15",
-      "moveSpeed": "",
-      "moveDir": "",
-      "move360": ""
-    },
-    {
-      "fxTime": "2021-07-28T20:00+08:00",
-      "lat": "33.1",
-      "lon": "117.2",
-      "type": "TD",
-      "pressure": "992",
-      "windSpeed": "15",
-      "moveSpeed": "",
-      "moveDir": "",
-      "move360": ""
-    },
-    {
-      "fxTime": "2021-07-29T08:00+08:00",
-      "lat": "34.3",
-      "lon": "117.2",
-      "type": "TD",
-      "pressure": "992",
-      "windSpeed": "15",
-      "moveSpeed": "",
-      "moveDir": "",
-      "move360": ""
-    },
-    {
-      "fxTime": "2021-07-29T20:00+08:00",
-      "lat": "36",
-      "lon": "117.8",
-      "type": "TD",
-      "pressure": "992",
-      "windSpeed": "15",
-      "moveSpeed": "",
-      "moveDir": "",
-      "move360": ""
-    },
-    {
-      "fxTime": "2021-07-30T08:00+08:00",
-      "lat": "37.1",
-      "lon": "118.7",
-      "type": "TD",
-      "pressure": "995",
-      "windSpeed": "15",
-      "moveSpeed": "",
-      "moveDir": "",
-      "move360": ""
-    },
-    {
-      "fxTime": "2021-07-31T08:00+08:00",
-      "lat": "38",
-      "lon": "119.8",
-      "type": "TD",
-      "pressure": "995",
-      "windSpeed": "15",
-      "moveSpeed": "",
-      "moveDir": "",
-      "move360": ""
-    }
-  ],
-  "refer": {
-    "sources": [
-      "NMC"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("storm_forecast", "success");
 
     let resp: StormForecastResponse = serde_json::from_str(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
     assert_eq!(resp.forecast.len(), 7);
     assert_eq!(resp.forecast[0].type_, "TS");
@@ -1,19 +1,296 @@
+use std::{fmt, str::FromStr};
+
 use serde::{Deserialize, Serialize};
 
 /// RGBA颜色
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
 pub struct RGBA {
     pub red: u8,
     pub green: u8,
     pub blue: u8,
-    pub alpha: u8,
+    /// 透明度，0-1
+    pub alpha: f32,
+}
+
+/// 颜色解析错误
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("invalid color: {0}")]
+pub struct ParseColorError(pub String);
+
+impl RGBA {
+    /// 黑色
+    pub const BLACK: RGBA = RGBA::new(0, 0, 0, 1.0);
+    /// 白色
+    pub const WHITE: RGBA = RGBA::new(255, 255, 255, 1.0);
+
+    /// 创建新的颜色
+    pub const fn new(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
+        RGBA {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// 16进制格式，例如 `#00E400FF`
+    pub fn to_hex(&self) -> String {
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.red,
+            self.green,
+            self.blue,
+            (self.alpha.clamp(0.0, 1.0) * 255.0).round() as u8
+        )
+    }
+
+    /// 解析16进制格式，支持 `#RGB`、`#RGBA`、`#RRGGBB` 和 `#RRGGBBAA`
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let error = || ParseColorError(hex.to_string());
+        let digits = hex.trim().strip_prefix('#').ok_or_else(error)?;
+        if !digits.is_ascii() {
+            return Err(error());
+        }
+        let channels = match digits.len() {
+            3 | 4 => digits
+                .chars()
+                .map(|c| u8::from_str_radix(&format!("{c}{c}"), 16))
+                .collect::<Result<Vec<_>, _>>(),
+            6 | 8 => (0..digits.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&digits[i..i + 2], 16))
+                .collect::<Result<Vec<_>, _>>(),
+            _ => return Err(error()),
+        }
+        .map_err(|_| error())?;
+        let alpha = channels.get(3).map_or(1.0, |alpha| *alpha as f32 / 255.0);
+
+        Ok(RGBA::new(channels[0], channels[1], channels[2], alpha))
+    }
+
+    /// CSS格式，例如 `rgba(0, 228, 0, 1)`
+    pub fn to_css(&self) -> String {
+        format!(
+            "rgba({}, {}, {}, {})",
+            self.red, self.green, self.blue, self.alpha
+        )
+    }
+
+    /// 解析CSS格式，支持 `rgb()` 和 `rgba()`
+    pub fn from_css(css: &str) -> Result<Self, ParseColorError> {
+        let error = || ParseColorError(css.to_string());
+        let css = css.trim();
+        let args = css
+            .strip_prefix("rgba(")
+            .or_else(|| css.strip_prefix("rgb("))
+            .and_then(|args| args.strip_suffix(')'))
+            .ok_or_else(error)?;
+        let args: Vec<&str> = args.split(',').map(str::trim).collect();
+        let channel = |i: usize| args[i].parse::<u8>().map_err(|_| error());
+        let alpha = match args.len() {
+            3 => 1.0,
+            4 => args[3]
+                .parse::<f32>()
+                .ok()
+                .filter(|alpha| (0.0..=1.0).contains(alpha))
+                .ok_or_else(error)?,
+            _ => return Err(error()),
+        };
+
+        Ok(RGBA::new(channel(0)?, channel(1)?, channel(2)?, alpha))
+    }
+
+    /// [WCAG 相对亮度](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance)，忽略透明度
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = |channel: u8| {
+            let c = channel as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.red) + 0.7152 * linear(self.green) + 0.0722 * linear(self.blue)
+    }
+
+    /// 与另一颜色的 [WCAG 对比度](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio)，取值 1-21
+    pub fn contrast_ratio(&self, other: &RGBA) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// 以此颜色为背景时对比度更高的文字颜色，黑色或白色
+    pub fn text_color(&self) -> RGBA {
+        if self.contrast_ratio(&RGBA::BLACK) >= self.contrast_ratio(&RGBA::WHITE) {
+            RGBA::BLACK
+        } else {
+            RGBA::WHITE
+        }
+    }
+}
+
+impl fmt::Display for RGBA {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl FromStr for RGBA {
+    type Err = ParseColorError;
+
+    /// 解析16进制或CSS格式
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim_start().starts_with('#') {
+            RGBA::from_hex(s)
+        } else {
+            RGBA::from_css(s)
+        }
+    }
+}
+
+/// 以16进制字符串序列化 [`RGBA`]，配合 `#[serde(with = "rgba_hex")]` 使用
+///
+/// 反序列化时同时支持16进制、CSS字符串以及接口返回的对象格式。
+pub mod rgba_hex {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use super::RGBA;
+
+    pub fn serialize<S>(color: &RGBA, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&color.to_hex())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<RGBA, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrStruct {
+            String(String),
+            Struct(RGBA),
+        }
+
+        match StringOrStruct::deserialize(deserializer)? {
+            StringOrStruct::String(s) => s.parse().map_err(D::Error::custom),
+            StringOrStruct::Struct(color) => Ok(color),
+        }
+    }
 }
 
 /// 元数据
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct MetaData {
     /// 数据标签
     pub tag: String,
     /// 数据来源或提供商名字以及他们的声明，开发者必须将此内容与当前数据一起展示，可能为空
     pub sources: Option<Vec<String>>,
 }
+
+/// 实现字符串枚举的解析、显示以及与字符串的比较，无法识别的值保存在 `Unknown` 中
+macro_rules! string_enum {
+    ($name:ident { $($variant:ident => $value:literal $(| $alias:literal)*),* $(,)? }) => {
+        impl $name {
+            /// 对应的字符串
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value $(| $alias)* => $name::$variant,)*
+                    _ => $name::Unknown(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $name::from(value.as_str())
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl ::std::str::FromStr for $name {
+            type Err = ::std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($name::from(s))
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                match self {
+                    $($name::$variant => matches!(other, $value $(| $alias)*),)*
+                    $name::Unknown(value) => value == other,
+                }
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self == *other
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
+
+#[test]
+fn test_rgba() {
+    let green = RGBA::new(0, 228, 0, 1.0);
+    assert_eq!(green.to_hex(), "#00E400FF");
+    assert_eq!(green.to_string(), "#00E400FF");
+    assert_eq!(green.to_css(), "rgba(0, 228, 0, 1)");
+    assert_eq!(RGBA::from_hex("#00E400FF").unwrap(), green);
+    assert_eq!(RGBA::from_hex("#00e400").unwrap(), green);
+    assert_eq!("rgba(0, 228, 0, 1)".parse::<RGBA>().unwrap(), green);
+    assert_eq!("rgb(0,228,0)".parse::<RGBA>().unwrap(), green);
+    assert_eq!(
+        RGBA::from_hex("#fff8").unwrap(),
+        RGBA::new(255, 255, 255, 136.0 / 255.0)
+    );
+    assert!(RGBA::from_hex("#00E40").is_err());
+    assert!(RGBA::from_hex("00E400").is_err());
+    assert!("rgba(0, 228, 0, 2)".parse::<RGBA>().is_err());
+    assert!("rgb(0, 256, 0)".parse::<RGBA>().is_err());
+
+    assert!((RGBA::BLACK.contrast_ratio(&RGBA::WHITE) - 21.0).abs() < 1e-9);
+    assert_eq!(green.text_color(), RGBA::BLACK);
+    assert_eq!(RGBA::new(126, 0, 35, 1.0).text_color(), RGBA::WHITE);
+
+    #[derive(Serialize, Deserialize)]
+    struct Colored {
+        #[serde(with = "rgba_hex")]
+        color: RGBA,
+    }
+    let json = serde_json::to_string(&Colored { color: green }).unwrap();
+    assert_eq!(json, r##"{"color":"#00E400FF"}"##);
+    let colored: Colored = serde_json::from_str(&json).unwrap();
+    assert_eq!(colored.color, green);
+    let colored: Colored =
+        serde_json::from_str(r#"{"color":{"red":0,"green":228,"blue":0,"alpha":1}}"#).unwrap();
+    assert_eq!(colored.color, green);
+}
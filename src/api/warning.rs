@@ -3,7 +3,11 @@ use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 use crate::{
-    api::{decode_datetime, option_decode_datetime, Refer},
+    api::{
+        decode_datetime, encode_datetime, option_decode_datetime, option_encode_datetime,
+        utils::{string_enum, RGBA},
+        RawResponse, Refer,
+    },
     client::QWeatherClient,
     APIResult,
 };
@@ -21,12 +25,20 @@ impl QWeatherClient {
     ///   最多支持小数点后两位），LocationID可通过GeoAPI获取。例如 location=101010100 或
     ///   location=116.41,39.92
     pub async fn weather_warning(&self, location: &str) -> APIResult<WeatherWarningResponse> {
+        Ok(self
+            .weather_warning_raw(location)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::weather_warning`] 的原始响应
+    pub async fn weather_warning_raw(&self, location: &str) -> APIResult<RawResponse> {
         let url = format!("{}/v7/warning/now", self.get_api_host());
 
         let mut params = BTreeMap::new();
         params.insert("location".to_string(), location.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 
     /// 天气预警城市列表
@@ -44,16 +56,24 @@ impl QWeatherClient {
         &self,
         range: &str,
     ) -> APIResult<WeatherWarningCityListResponse> {
+        Ok(self
+            .weather_warning_city_list_raw(range)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::weather_warning_city_list`] 的原始响应
+    pub async fn weather_warning_city_list_raw(&self, range: &str) -> APIResult<RawResponse> {
         let url = format!("{}/v7/warning/list", self.get_api_host());
 
         let mut params = BTreeMap::new();
         params.insert("range".to_string(), range.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherWarning {
     /// 本条预警的唯一标识，可判断本条预警是否已经存在
@@ -61,22 +81,31 @@ pub struct WeatherWarning {
     /// 预警发布单位，可能为空
     pub sender: String,
     /// 预警发布时间
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub pub_time: DateTime<FixedOffset>,
     /// 预警信息标题
     pub title: String,
     /// 预警开始时间，可能为空
-    #[serde(deserialize_with = "option_decode_datetime")]
+    #[serde(
+        deserialize_with = "option_decode_datetime",
+        serialize_with = "option_encode_datetime"
+    )]
     pub start_time: Option<DateTime<FixedOffset>>,
     /// [预警结束时间](https://dev.qweather.com/docs/resource/warning-info/#expiry-time)，可能为空
-    #[serde(deserialize_with = "option_decode_datetime")]
+    #[serde(
+        deserialize_with = "option_decode_datetime",
+        serialize_with = "option_encode_datetime"
+    )]
     pub end_time: Option<DateTime<FixedOffset>>,
     /// [预警信息的发布状态](https://dev.qweather.com/docs/resource/warning-info/#status)
     pub status: String,
     /// [预警严重等级](https://dev.qweather.com/docs/resource/warning-info/#severity)
     pub severity: String,
     /// [预警严重等级颜色](https://dev.qweather.com/docs/resource/warning-info/#severity-color)，可能为空
-    pub severity_color: String,
+    pub severity_color: SeverityColor,
     /// [预警类型ID](https://dev.qweather.com/docs/resource/warning-info/#warning-type)
     #[serde(rename = "type")]
     pub type_: String,
@@ -92,14 +121,68 @@ pub struct WeatherWarning {
     pub related: String,
 }
 
+/// [预警严重等级颜色](https://dev.qweather.com/docs/resource/warning-info/#severity-color)
+///
+/// 解析时区分大小写，无法识别的值（包括空字符串）不会报错，而是保存在 `Unknown` 中，
+/// 序列化时原样输出
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum SeverityColor {
+    /// 白色
+    White,
+    /// 蓝色
+    Blue,
+    /// 绿色
+    Green,
+    /// 黄色
+    Yellow,
+    /// 橙色
+    Orange,
+    /// 红色
+    Red,
+    /// 黑色
+    Black,
+    /// 其他颜色，保留原始字符串，可能为空
+    Unknown(String),
+}
+
+string_enum!(SeverityColor {
+    White => "White",
+    Blue => "Blue",
+    Green => "Green",
+    Yellow => "Yellow",
+    Orange => "Orange",
+    Red => "Red",
+    Black => "Black",
+});
+
+impl SeverityColor {
+    /// 对应的RGBA颜色，取自CSS颜色名称，未知颜色返回 `None`
+    pub fn rgba(&self) -> Option<RGBA> {
+        match self {
+            SeverityColor::White => Some(RGBA::new(255, 255, 255, 1.0)),
+            SeverityColor::Blue => Some(RGBA::new(0, 0, 255, 1.0)),
+            SeverityColor::Green => Some(RGBA::new(0, 128, 0, 1.0)),
+            SeverityColor::Yellow => Some(RGBA::new(255, 255, 0, 1.0)),
+            SeverityColor::Orange => Some(RGBA::new(255, 165, 0, 1.0)),
+            SeverityColor::Red => Some(RGBA::new(255, 0, 0, 1.0)),
+            SeverityColor::Black => Some(RGBA::new(0, 0, 0, 1.0)),
+            SeverityColor::Unknown(_) => None,
+        }
+    }
+}
+
 /// 天气灾害预警返回数据
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherWarningResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     /// 当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前数据的响应式页面，便于嵌入网站或应用
     pub fx_link: String,
@@ -107,25 +190,34 @@ pub struct WeatherWarningResponse {
     pub warning: Vec<WeatherWarning>,
     /// 数据来源
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 天气预警城市列表
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherWarningCityListResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     /// 当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前国家预警的LocationID
     pub warning_loc_list: Vec<LocationId>,
     /// 数据来源
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// LocationID
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LocationId {
     pub location_id: String,
@@ -133,41 +225,10 @@ pub struct LocationId {
 
 #[test]
 fn test_weather_warning() {
-    let json_data = r#"{
-  "code": "200",
-  "updateTime": "2023-04-03T14:20+08:00",
-  "fxLink": "https://www.qweather.com/severe-weather/shanghai-101020100.html",
-  "warning": [
-    {
-      "id": "10102010020230403103000500681616",
-      "sender": "上海中心气象台",
-      "pubTime": "2023-04-03T10:30+08:00",
-      "title": "上海中心气象台发布大风蓝色预警[Ⅳ级/一般]",
-      "startTime": "2023-04-03T10:30+08:00",
-      "endTime": "2023-04-04T10:30+08:00",
-      "status": "active",
-      "level": "",
-      "severity": "Minor",
-      "severityColor": "Blue",
-      "type": "1006",
-      "typeName": "大风",
-      "urgency": "",
-      "certainty": "",
-      "text": "上海中心气象台2023年04月03日10时30分发布大风蓝色预警[Ⅳ级/一般]：受江淮气旋影响，预计明天傍晚以前本市大部地区将出现6级阵风7-8级的东南大风，沿江沿海地区7级阵风8-9级，请注意防范大风对高空作业、交通出行、设施农业等的不利影响。",
-      "related": ""
-    }
-  ],
-  "refer": {
-    "sources": [
-      "12379"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("weather_warning", "success");
 
     let resp: WeatherWarningResponse = serde_json::from_str(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
     assert_eq!(
         resp.fx_link,
@@ -188,6 +249,20 @@ fn test_weather_warning() {
     assert_eq!(warning.status, "active");
     assert_eq!(warning.severity, "Minor");
     assert_eq!(warning.severity_color, "Blue");
+    assert_eq!(warning.severity_color.rgba().unwrap().to_hex(), "#0000FFFF");
+    assert_eq!(
+        warning.severity_color.rgba().unwrap().text_color(),
+        RGBA::WHITE
+    );
+    assert_eq!(
+        SeverityColor::from(""),
+        SeverityColor::Unknown(String::new())
+    );
+    assert_eq!(
+        SeverityColor::from("blue"),
+        SeverityColor::Unknown("blue".to_string())
+    );
+    assert_eq!(String::from(SeverityColor::from("Purple")), "Purple");
     assert_eq!(warning.type_, "1006");
     assert_eq!(warning.type_name, "大风");
     assert_eq!(warning.urgency, "");
@@ -198,110 +273,10 @@ fn test_weather_warning() {
 
 #[test]
 fn test_weather_warning_city_list() {
-    let json_data = r#"{
-  "code": "200",
-  "updateTime": "2020-06-21T05:39+00:00",
-  "warningLocList": [
-    {
-      "locationId": "101010800"
-    },
-    {
-      "locationId": "101011200"
-    },
-    {
-      "locationId": "101011400"
-    },
-    {
-      "locationId": "101020700"
-    },
-    {
-      "locationId": "101040400"
-    },
-    {
-      "locationId": "101041900"
-    },
-    {
-      "locationId": "101043400"
-    },
-    {
-      "locationId": "101043600"
-    },
-    {
-      "locationId": "101050106"
-    },
-    {
-      "locationId": "101050107"
-    },
-    {
-      "locationId": "101050301"
-    },
-    {
-      "locationId": "101050302"
-    },
-    {
-      "locationId": "101050303"
-    },
-    {
-      "locationId": "101130103"
-    },
-    {
-      "locationId": "101130109"
-    },
-    {
-      "locationId": "101130114"
-    },
-    {
-      "locationId": "101130302"
-    },
-    {
-      "locationId": "101130303"
-    },
-    {
-      "locationId": "101130409"
-    },
-    {
-      "locationId": "101130610"
-    },
-    {
-      "locationId": "101130611"
-    },
-    {
-      "locationId": "101130613"
-    },
-    {
-      "locationId": "101130614"
-    },
-    {
-      "locationId": "101131920"
-    },
-    {
-      "locationId": "101221008"
-    },
-    {
-      "locationId": "101230507"
-    },
-    {
-      "locationId": "101132101"
-    },
-    {
-      "locationId": "101132201"
-    },
-    {
-      "locationId": "101132301"
-    }
-  ],
-  "refer": {
-    "sources": [
-      "12379",
-      "QWeather"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("weather_warning_city_list", "success");
 
     let resp: WeatherWarningCityListResponse = serde_json::from_str(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
     assert_eq!(
         resp.update_time,
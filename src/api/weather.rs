@@ -5,7 +5,10 @@ use serde::{Deserialize, Serialize};
 use serde_aux::prelude::deserialize_number_from_string;
 
 use crate::{
-    api::{decode_datetime, deserialize_option_number_from_empty_string, Refer},
+    api::{
+        decode_datetime, deserialize_option_number_from_empty_string, encode_datetime, RawResponse,
+        Refer,
+    },
     client::QWeatherClient,
     APIResult,
 };
@@ -22,11 +25,19 @@ impl QWeatherClient {
     ///   最多支持小数点后两位），LocationID可通过GeoAPI获取。例如 location=101010100 或
     ///   location=116.41,39.92
     pub async fn weather_now(&self, location: &str) -> APIResult<WeatherNowResponse> {
+        Ok(self
+            .weather_now_raw(location)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::weather_now`] 的原始响应
+    pub async fn weather_now_raw(&self, location: &str) -> APIResult<RawResponse> {
         let url = format!("{}/v7/weather/now", self.get_api_host());
         let mut params = BTreeMap::new();
         params.insert("location".to_string(), location.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 
     /// 每日天气预报
@@ -47,6 +58,18 @@ impl QWeatherClient {
         location: &str,
         day: u8,
     ) -> APIResult<WeatherDailyForecastResponse> {
+        Ok(self
+            .weather_daily_forecast_raw(location, day)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::weather_daily_forecast`] 的原始响应
+    pub async fn weather_daily_forecast_raw(
+        &self,
+        location: &str,
+        day: u8,
+    ) -> APIResult<RawResponse> {
         if ![3u8, 7, 10, 15, 30].contains(&day) {
             panic!("invalid day")
         }
@@ -54,7 +77,7 @@ impl QWeatherClient {
         let mut params = BTreeMap::new();
         params.insert("location".to_string(), location.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 
     /// 逐小时天气预报
@@ -74,6 +97,18 @@ impl QWeatherClient {
         location: &str,
         hour: u8,
     ) -> APIResult<WeatherHourlyForecastResponse> {
+        Ok(self
+            .weather_hourly_forecast_raw(location, hour)
+            .await?
+            .and_then(RawResponse::decode))
+    }
+
+    /// [`Self::weather_hourly_forecast`] 的原始响应
+    pub async fn weather_hourly_forecast_raw(
+        &self,
+        location: &str,
+        hour: u8,
+    ) -> APIResult<RawResponse> {
         if ![24u8, 72, 168].contains(&hour) {
             panic!("invalid hour")
         }
@@ -81,16 +116,19 @@ impl QWeatherClient {
         let mut params = BTreeMap::new();
         params.insert("location".to_string(), location.to_string());
 
-        self.request_api(url, params).await
+        self.request_raw(url, params).await
     }
 }
 
 /// 实时天气返回值`
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherNow {
     /// 数据观测时间
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub obs_time: DateTime<FixedOffset>,
     /// 温度，默认单位：摄氏度
     #[serde(deserialize_with = "deserialize_number_from_string")]
@@ -134,13 +172,16 @@ pub struct WeatherNow {
 }
 
 /// 实时天气返回数据
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherNowResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     /// 当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前数据的响应式页面，便于嵌入网站或应用
     pub fx_link: String,
@@ -148,10 +189,13 @@ pub struct WeatherNowResponse {
     pub now: WeatherNow,
     /// 数据来源
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 每日天气预报
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DailyForecast {
     /// 预报日期
@@ -223,13 +267,16 @@ pub struct DailyForecast {
 }
 
 /// 每日天气预报返回数据
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherDailyForecastResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     /// 当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前数据的响应式页面，便于嵌入网站或应用
     pub fx_link: String,
@@ -237,14 +284,20 @@ pub struct WeatherDailyForecastResponse {
     pub daily: Vec<DailyForecast>,
     /// 数据来源
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 逐小时天气预报
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct HourlyForecast {
     /// 预报时间
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub fx_time: DateTime<FixedOffset>,
     /// 温度，默认单位：摄氏度
     #[serde(deserialize_with = "deserialize_number_from_string")]
@@ -281,13 +334,16 @@ pub struct HourlyForecast {
 }
 
 /// 逐小时天气预报返回数据
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WeatherHourlyForecastResponse {
     /// 请参考[状态码](https://dev.qweather.com/docs/resource/status-code/)
     pub code: String,
     /// 当前[API的最近更新时间](https://dev.qweather.com/docs/resource/glossary/#update-time)
-    #[serde(deserialize_with = "decode_datetime")]
+    #[serde(
+        deserialize_with = "decode_datetime",
+        serialize_with = "encode_datetime"
+    )]
     pub update_time: DateTime<FixedOffset>,
     /// 当前数据的响应式页面，便于嵌入网站或应用
     pub fx_link: String,
@@ -295,599 +351,69 @@ pub struct WeatherHourlyForecastResponse {
     pub hourly: Vec<HourlyForecast>,
     /// 数据来源
     pub refer: Refer,
+    /// 未知字段，接口新增的字段保留在这里
+    #[serde(flatten, default)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[test]
 fn test_weather_now() {
-    let json_data = r#"{
-  "code": "200",
-  "updateTime": "2020-06-30T22:00+08:00",
-  "fxLink": "http://hfx.link/2ax1",
-  "now": {
-    "obsTime": "2020-06-30T21:40+08:00",
-    "temp": "24",
-    "feelsLike": "26",
-    "icon": "101",
-    "text": "多云",
-    "wind360": "123",
-    "windDir": "东南风",
-    "windScale": "1",
-    "windSpeed": "3",
-    "humidity": "72",
-    "precip": "0.0",
-    "pressure": "1003",
-    "vis": "16",
-    "cloud": "10",
-    "dew": "21"
-  },
-  "refer": {
-    "sources": [
-      "QWeather",
-      "NMC",
-      "ECMWF"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("weather_now", "success");
 
     let resp = serde_json::from_str::<WeatherNowResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
 
-    let json_data = serde_json::json!(
-          {
-            "code": "200",
-            "updateTime": "2024-07-03T18:26+08:00",
-            "fxLink": "https://www.qweather.com/weather/beijing-101010100.html",
-            "now": {
-            "obsTime": "2024-07-03T18:24+08:00",
-            "temp": "27",
-            "feelsLike": "28",
-            "icon": "101",
-            "text": "多云",
-            "wind360": "297",
-            "windDir": "西北风",
-            "windScale": "1",
-            "windSpeed": "4",
-            "humidity": "51",
-            "precip": "0.0",
-            "pressure": "1001",
-            "vis": "17",
-            "cloud": "",
-            "dew": ""
-            },
-            "refer": {
-            "sources": [
-            "QWeather"
-            ],
-            "license": [
-            "CC BY-SA 4.0"
-            ]
-    }
-    }
-
-        );
+    let json_data = crate::fixtures::json("weather_now", "empty_cloud_dew");
 
     let resp = serde_json::from_value::<WeatherNowResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.code, "200");
+    assert_eq!(resp.now.cloud, None);
+
+    let resp: WeatherNowResponse =
+        serde_json::from_str(crate::fixtures::body("weather_now", "imperial")).unwrap();
+    assert_eq!(resp.now.temp, 75.0);
+    assert_eq!(resp.now.vis, 10.0);
+
+    let resp: WeatherNowResponse =
+        serde_json::from_str(crate::fixtures::body("weather_now", "english")).unwrap();
+    assert_eq!(resp.now.text, "Cloudy");
+    assert_eq!(resp.now.wind_dir, "SE");
+
+    // 错误状态码按 `code` 返回，不解析其余字段
+    for fixture in crate::fixtures::endpoint("weather_now") {
+        let raw = crate::api::RawResponse {
+            status: 200,
+            headers: Default::default(),
+            body: fixture.json(),
+        };
+        let code = raw.code().unwrap().to_string();
+        match raw.decode::<WeatherNowResponse>() {
+            crate::api::APIResponse::Success(resp) => {
+                assert_eq!(code, "200", "{}", fixture.name);
+                crate::fixtures::assert_round_trip(&resp);
+            }
+            crate::api::APIResponse::Error(error) => assert_eq!(error, code),
+            other => panic!("{}: unexpected {:?}", fixture.name, other),
+        }
+    }
 }
 
 #[test]
 fn test_weather_daily_forecast() {
-    let json_data = r#"{
-  "code": "200",
-  "updateTime": "2021-11-15T16:35+08:00",
-  "fxLink": "http://hfx.link/2ax1",
-  "daily": [
-    {
-      "fxDate": "2021-11-15",
-      "sunrise": "06:58",
-      "sunset": "16:59",
-      "moonrise": "15:16",
-      "moonset": "03:40",
-      "moonPhase": "盈凸月",
-      "moonPhaseIcon": "803",
-      "tempMax": "12",
-      "tempMin": "-1",
-      "iconDay": "101",
-      "textDay": "多云",
-      "iconNight": "150",
-      "textNight": "晴",
-      "wind360Day": "45",
-      "windDirDay": "东北风",
-      "windScaleDay": "1-2",
-      "windSpeedDay": "3",
-      "wind360Night": "0",
-      "windDirNight": "北风",
-      "windScaleNight": "1-2",
-      "windSpeedNight": "3",
-      "humidity": "65",
-      "precip": "0.0",
-      "pressure": "1020",
-      "vis": "25",
-      "cloud": "4",
-      "uvIndex": "3"
-    },
-    {
-      "fxDate": "2021-11-16",
-      "sunrise": "07:00",
-      "sunset": "16:58",
-      "moonrise": "15:38",
-      "moonset": "04:40",
-      "moonPhase": "盈凸月",
-      "moonPhaseIcon": "803",
-      "tempMax": "13",
-      "tempMin": "0",
-      "iconDay": "100",
-      "textDay": "晴",
-      "iconNight": "101",
-      "textNight": "多云",
-      "wind360Day": "225",
-      "windDirDay": "西南风",
-      "windScaleDay": "1-2",
-      "windSpeedDay": "3",
-      "wind360Night": "225",
-      "windDirNight": "西南风",
-      "windScaleNight": "1-2",
-      "windSpeedNight": "3",
-      "humidity": "74",
-      "precip": "0.0",
-      "pressure": "1016",
-      "vis": "25",
-      "cloud": "1",
-      "uvIndex": "3"
-    },
-    {
-      "fxDate": "2021-11-17",
-      "sunrise": "07:01",
-      "sunset": "16:57",
-      "moonrise": "16:01",
-      "moonset": "05:41",
-      "moonPhase": "盈凸月",
-      "moonPhaseIcon": "803",
-      "tempMax": "13",
-      "tempMin": "0",
-      "iconDay": "100",
-      "textDay": "晴",
-      "iconNight": "150",
-      "textNight": "晴",
-      "wind360Day": "225",
-      "windDirDay": "西南风",
-      "windScaleDay": "1-2",
-      "windSpeedDay": "3",
-      "wind360Night": "225",
-      "windDirNight": "西南风",
-      "windScaleNight": "1-2",
-      "windSpeedNight": "3",
-      "humidity": "56",
-      "precip": "0.0",
-      "pressure": "1009",
-      "vis": "25",
-      "cloud": "0",
-      "uvIndex": "3"
-    }
-  ],
-  "refer": {
-    "sources": [
-      "QWeather",
-      "NMC",
-      "ECMWF"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("weather_daily_forecast", "success");
 
     let resp = serde_json::from_str::<WeatherDailyForecastResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.daily.len(), 3)
 }
 
 #[test]
 fn test_hourly_forecast() {
-    let json_data = r#"{
-  "code": "200",
-  "updateTime": "2021-02-16T13:35+08:00",
-  "fxLink": "http://hfx.link/2ax1",
-  "hourly": [
-    {
-      "fxTime": "2021-02-16T15:00+08:00",
-      "temp": "2",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "335",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "20",
-      "humidity": "11",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "0",
-      "dew": "-25"
-    },
-    {
-      "fxTime": "2021-02-16T16:00+08:00",
-      "temp": "1",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "339",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "24",
-      "humidity": "11",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "0",
-      "dew": "-26"
-    },
-    {
-      "fxTime": "2021-02-16T17:00+08:00",
-      "temp": "0",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "341",
-      "windDir": "西北风",
-      "windScale": "4-5",
-      "windSpeed": "25",
-      "humidity": "11",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1026",
-      "cloud": "0",
-      "dew": "-26"
-    },
-    {
-      "fxTime": "2021-02-16T18:00+08:00",
-      "temp": "0",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "344",
-      "windDir": "西北风",
-      "windScale": "4-5",
-      "windSpeed": "25",
-      "humidity": "12",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-16T19:00+08:00",
-      "temp": "-2",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "349",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "24",
-      "humidity": "13",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-16T20:00+08:00",
-      "temp": "-3",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "353",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "22",
-      "humidity": "14",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-16T21:00+08:00",
-      "temp": "-3",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "355",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "20",
-      "humidity": "14",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1026",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-16T22:00+08:00",
-      "temp": "-4",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "356",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "18",
-      "humidity": "16",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1026",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-16T23:00+08:00",
-      "temp": "-4",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "356",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "18",
-      "humidity": "16",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1026",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-17T00:00+08:00",
-      "temp": "-4",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "354",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "16",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1027",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-17T01:00+08:00",
-      "temp": "-4",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "351",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "16",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1028",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-17T02:00+08:00",
-      "temp": "-4",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "350",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "16",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1028",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-17T03:00+08:00",
-      "temp": "-5",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "350",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "16",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1028",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-17T04:00+08:00",
-      "temp": "-5",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "351",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "15",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1027",
-      "cloud": "0",
-      "dew": "-28"
-    },
-    {
-      "fxTime": "2021-02-17T05:00+08:00",
-      "temp": "-5",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "352",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "14",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1026",
-      "cloud": "0",
-      "dew": "-29"
-    },
-    {
-      "fxTime": "2021-02-17T06:00+08:00",
-      "temp": "-5",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "355",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "14",
-      "humidity": "16",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "0",
-      "dew": "-27"
-    },
-    {
-      "fxTime": "2021-02-17T07:00+08:00",
-      "temp": "-7",
-      "icon": "150",
-      "text": "晴",
-      "wind360": "359",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "20",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1024",
-      "cloud": "0",
-      "dew": "-26"
-    },
-    {
-      "fxTime": "2021-02-17T08:00+08:00",
-      "temp": "-5",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "1",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "14",
-      "humidity": "19",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1023",
-      "cloud": "0",
-      "dew": "-26"
-    },
-    {
-      "fxTime": "2021-02-17T09:00+08:00",
-      "temp": "-4",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "356",
-      "windDir": "北风",
-      "windScale": "3-4",
-      "windSpeed": "14",
-      "humidity": "17",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1023",
-      "cloud": "0",
-      "dew": "-25"
-    },
-    {
-      "fxTime": "2021-02-17T10:00+08:00",
-      "temp": "-1",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "344",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "14",
-      "humidity": "14",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1024",
-      "cloud": "0",
-      "dew": "-26"
-    },
-    {
-      "fxTime": "2021-02-17T11:00+08:00",
-      "temp": "0",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "333",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "14",
-      "humidity": "12",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1024",
-      "cloud": "0",
-      "dew": "-26"
-    },
-    {
-      "fxTime": "2021-02-17T12:00+08:00",
-      "temp": "1",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "325",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "14",
-      "humidity": "10",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "16",
-      "dew": "-28"
-    },
-    {
-      "fxTime": "2021-02-17T13:00+08:00",
-      "temp": "2",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "319",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "8",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "32",
-      "dew": "-29"
-    },
-    {
-      "fxTime": "2021-02-17T14:00+08:00",
-      "temp": "2",
-      "icon": "100",
-      "text": "晴",
-      "wind360": "313",
-      "windDir": "西北风",
-      "windScale": "3-4",
-      "windSpeed": "16",
-      "humidity": "9",
-      "pop": "0",
-      "precip": "0.0",
-      "pressure": "1025",
-      "cloud": "48",
-      "dew": "-27"
-    }
-  ],
-  "refer": {
-    "sources": [
-      "QWeather",
-      "NMC",
-      "ECMWF"
-    ],
-    "license": [
-      "QWeather Developers License"
-    ]
-  }
-}"#;
+    let json_data = crate::fixtures::body("weather_hourly_forecast", "success");
 
     let resp = serde_json::from_str::<WeatherHourlyForecastResponse>(json_data).unwrap();
+    crate::fixtures::assert_round_trip(&resp);
     assert_eq!(resp.hourly.len(), 24)
 }
@@ -0,0 +1,283 @@
+//! 批量请求
+//!
+//! 以有限的并发数请求多个地点，结果按输入顺序返回。设置 [`BatchOptions::stop_on_fatal`] 后，
+//! 遇到认证失败、额度不足等后续请求也必然失败的错误时停止，不再发送剩余的请求。
+//!
+//! ```no_run
+//! use qweather_sdk::{batch::BatchOptions, client::{ClientConfig, QWeatherClient}};
+//!
+//! # async fn run() {
+//! let client = QWeatherClient::with_config(ClientConfig::new("id", "key"));
+//! let options = BatchOptions::new(16);
+//! for (location, result) in client.weather_now_many(&["101010100", "101020100"], options).await {
+//!     println!("{}: {:?}", location, result);
+//! }
+//! # }
+//! ```
+
+use std::{
+    future::{ready, Future},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use futures_util::{stream, Stream, StreamExt};
+
+use crate::{
+    api::{
+        air_quality::AirCurrentResponse,
+        warning::WeatherWarningResponse,
+        weather::{
+            WeatherDailyForecastResponse, WeatherHourlyForecastResponse, WeatherNowResponse,
+        },
+        APIResponse,
+    },
+    client::QWeatherClient,
+    APIResult, Error,
+};
+
+/// 批量请求选项
+#[derive(Debug, Clone, Copy)]
+pub struct BatchOptions {
+    /// 最大并发数，默认 8
+    pub concurrency: usize,
+    /// 遇到认证失败、额度不足等错误时停止，默认不停止
+    pub stop_on_fatal: bool,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions {
+            concurrency: 8,
+            stop_on_fatal: false,
+        }
+    }
+}
+
+impl BatchOptions {
+    /// 最大并发数为 `concurrency`，其他设置使用默认值
+    pub fn new(concurrency: usize) -> Self {
+        BatchOptions {
+            concurrency,
+            ..Default::default()
+        }
+    }
+
+    /// 遇到认证失败、额度不足等错误时停止
+    pub fn stop_on_fatal(mut self, stop_on_fatal: bool) -> Self {
+        self.stop_on_fatal = stop_on_fatal;
+        self
+    }
+}
+
+/// 是否为后续请求也必然失败的错误
+///
+/// 包括本地额度用完，以及[状态码](https://dev.qweather.com/docs/resource/status-code/)
+/// 401（认证失败）、402（超过访问次数或余额不足）、403（无访问权限）。
+pub fn is_fatal<T>(result: &APIResult<T>) -> bool {
+    match result {
+        Err(Error::QuotaExhausted { .. }) => true,
+        Ok(APIResponse::Error(code)) => matches!(code.as_str(), "401" | "402" | "403"),
+        _ => false,
+    }
+}
+
+impl QWeatherClient {
+    /// 批量请求，以流的形式按输入顺序返回每个键及其结果
+    ///
+    /// 停止时正在执行的请求会被取消，剩余的键不会返回。
+    pub fn batch_stream<'a, I, K, T, F, Fut>(
+        &'a self,
+        keys: I,
+        options: BatchOptions,
+        f: F,
+    ) -> impl Stream<Item = (K, APIResult<T>)> + 'a
+    where
+        I: IntoIterator<Item = K>,
+        I::IntoIter: 'a,
+        K: Clone + 'a,
+        T: 'a,
+        F: Fn(&'a QWeatherClient, K) -> Fut + 'a,
+        Fut: Future<Output = APIResult<T>> + 'a,
+    {
+        // 停止后不再创建新的请求
+        let stopped = Arc::new(AtomicBool::new(false));
+        let requests = {
+            let stopped = stopped.clone();
+            keys.into_iter()
+                .take_while(move |_| !stopped.load(Ordering::Relaxed))
+                .map(move |key| {
+                    let request = f(self, key.clone());
+                    async move { (key, request.await) }
+                })
+        };
+
+        stream::iter(requests)
+            .buffered(options.concurrency.max(1))
+            .scan((), move |_, item| {
+                if stopped.load(Ordering::Relaxed) {
+                    return ready(None);
+                }
+                if options.stop_on_fatal && is_fatal(&item.1) {
+                    stopped.store(true, Ordering::Relaxed);
+                }
+                ready(Some(item))
+            })
+    }
+
+    /// 批量请求，按输入顺序返回每个键及其结果
+    ///
+    /// ```no_run
+    /// # use qweather_sdk::{batch::BatchOptions, client::{ClientConfig, QWeatherClient}};
+    /// # async fn run(client: QWeatherClient) {
+    /// let results = client
+    ///     .batch(["101010100", "101020100"], BatchOptions::new(4), |client, location| {
+    ///         client.indices_forecast(location, "1,2", 1)
+    ///     })
+    ///     .await;
+    /// # }
+    /// ```
+    pub async fn batch<'a, I, K, T, F, Fut>(
+        &'a self,
+        keys: I,
+        options: BatchOptions,
+        f: F,
+    ) -> Vec<(K, APIResult<T>)>
+    where
+        I: IntoIterator<Item = K>,
+        I::IntoIter: 'a,
+        K: Clone + 'a,
+        T: 'a,
+        F: Fn(&'a QWeatherClient, K) -> Fut + 'a,
+        Fut: Future<Output = APIResult<T>> + 'a,
+    {
+        self.batch_stream(keys, options, f).collect().await
+    }
+
+    /// 批量获取实时天气，参见 [`QWeatherClient::weather_now`]
+    pub async fn weather_now_many<'a>(
+        &'a self,
+        locations: &[&'a str],
+        options: BatchOptions,
+    ) -> Vec<(&'a str, APIResult<WeatherNowResponse>)> {
+        self.batch(locations.iter().copied(), options, |client, location| {
+            client.weather_now(location)
+        })
+        .await
+    }
+
+    /// 批量获取每日天气预报，参见 [`QWeatherClient::weather_daily_forecast`]
+    pub async fn weather_daily_forecast_many<'a>(
+        &'a self,
+        locations: &[&'a str],
+        day: u8,
+        options: BatchOptions,
+    ) -> Vec<(&'a str, APIResult<WeatherDailyForecastResponse>)> {
+        self.batch(
+            locations.iter().copied(),
+            options,
+            move |client, location| client.weather_daily_forecast(location, day),
+        )
+        .await
+    }
+
+    /// 批量获取逐小时天气预报，参见 [`QWeatherClient::weather_hourly_forecast`]
+    pub async fn weather_hourly_forecast_many<'a>(
+        &'a self,
+        locations: &[&'a str],
+        hour: u8,
+        options: BatchOptions,
+    ) -> Vec<(&'a str, APIResult<WeatherHourlyForecastResponse>)> {
+        self.batch(
+            locations.iter().copied(),
+            options,
+            move |client, location| client.weather_hourly_forecast(location, hour),
+        )
+        .await
+    }
+
+    /// 批量获取天气灾害预警，参见 [`QWeatherClient::weather_warning`]
+    pub async fn weather_warning_many<'a>(
+        &'a self,
+        locations: &[&'a str],
+        options: BatchOptions,
+    ) -> Vec<(&'a str, APIResult<WeatherWarningResponse>)> {
+        self.batch(locations.iter().copied(), options, |client, location| {
+            client.weather_warning(location)
+        })
+        .await
+    }
+
+    /// 批量获取实时空气质量，坐标为 (纬度, 经度)，参见 [`QWeatherClient::air_current`]
+    pub async fn air_current_many(
+        &self,
+        coordinates: &[(f64, f64)],
+        options: BatchOptions,
+    ) -> Vec<((f64, f64), APIResult<AirCurrentResponse>)> {
+        self.batch(
+            coordinates.iter().copied(),
+            options,
+            |client, (latitude, longitude)| client.air_current(latitude, longitude),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_batch() {
+    use crate::{
+        client::ClientConfig,
+        transport::{MockTransport, Response},
+    };
+
+    let transport = MockTransport::with_handler(|request| {
+        let location = request.params["location"].as_str();
+        let body = match location {
+            "401" => serde_json::json!({ "code": "401" }),
+            _ => serde_json::json!({ "code": "200", "location": location }),
+        };
+        Ok(Response::json(&body))
+    });
+    let client = QWeatherClient::with_transport(ClientConfig::new("id", "key"), transport.clone());
+    fn request<'a>(
+        client: &'a QWeatherClient,
+        location: &str,
+    ) -> impl Future<Output = APIResult<serde_json::Value>> + 'a {
+        let mut params = std::collections::BTreeMap::new();
+        params.insert("location".to_string(), location.to_string());
+        client.request_api(
+            "https://devapi.qweather.com/v7/weather/now".to_string(),
+            params,
+        )
+    }
+    let locations = ["1", "2", "401", "3", "4"];
+
+    let results = client
+        .batch(locations, BatchOptions::new(2), |client, location| {
+            request(client, location)
+        })
+        .await;
+    assert_eq!(results.len(), 5);
+    for (location, result) in &results {
+        match result.as_ref().unwrap() {
+            APIResponse::Success(value) => assert_eq!(value["location"], *location),
+            APIResponse::Error(code) => assert_eq!(code, location),
+            other => panic!("unexpected response {:?}", other),
+        }
+    }
+    assert_eq!(transport.requests().len(), 5);
+
+    let options = BatchOptions::new(1).stop_on_fatal(true);
+    let results = client
+        .batch(locations, options, |client, location| {
+            request(client, location)
+        })
+        .await;
+    let keys: Vec<_> = results.iter().map(|(location, _)| *location).collect();
+    assert_eq!(keys, ["1", "2", "401"]);
+    assert!(is_fatal(&results[2].1));
+    assert_eq!(transport.requests().len(), 8);
+}
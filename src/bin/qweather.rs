@@ -0,0 +1,800 @@
+//! 和风天气命令行工具
+//!
+//! 从环境变量或 `.env` 文件读取 `QWEATHER_ID`、`QWEATHER_KEY`，可选的 `QWEATHER_API_HOST` 为自定义API Host。
+//! 地点可以是LocationID、`经度,纬度` 坐标或城市名称，城市名称通过城市搜索转换为LocationID或坐标。
+//!
+//! ```text
+//! qweather now 北京
+//! qweather daily 101010100 --days 7 --output csv
+//! qweather air 116.41,39.92 --hourly --lang en
+//! qweather geo city beij --number 5
+//! ```
+
+use std::{env, process::ExitCode};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use serde_json::Value;
+
+use qweather_sdk::{
+    api::{
+        geo::{CityLookupInput, GeoPoiLookupInput, GeoPoiRangeInput, Location},
+        APIResponse,
+    },
+    client::{ClientConfig, QWeatherClient},
+    APIResult,
+};
+
+/// 和风天气命令行工具
+#[derive(Debug, Parser)]
+#[command(name = "qweather", version)]
+struct Cli {
+    /// 多语言设置，例如 zh、en
+    #[arg(long, global = true)]
+    lang: Option<String>,
+    /// 数据单位，m 为公制，i 为英制
+    #[arg(long, global = true, value_parser = ["m", "i"])]
+    unit: Option<String>,
+    /// 输出格式
+    #[arg(long, short, global = true, value_enum, default_value_t = Format::Table)]
+    output: Format,
+    #[command(subcommand)]
+    command: Command,
+}
+
+impl Cli {
+    /// 应用命令行中的语言和单位设置
+    fn configure(&self, mut config: ClientConfig) -> ClientConfig {
+        if let Some(lang) = &self.lang {
+            config.lang = Some(lang.clone());
+        }
+        config.unit = self.unit.clone();
+        config
+    }
+}
+
+/// 输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// 对齐的表格
+    Table,
+    /// 完整的JSON响应
+    Json,
+    /// CSV，第一行为表头
+    Csv,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// 实时天气
+    Now {
+        /// LocationID、经度,纬度坐标或城市名称
+        location: String,
+    },
+    /// 每日天气预报
+    Daily {
+        /// LocationID、经度,纬度坐标或城市名称
+        location: String,
+        /// 预报天数，3、7、10、15或30
+        #[arg(long, default_value_t = 3)]
+        days: u8,
+    },
+    /// 逐小时天气预报
+    Hourly {
+        /// LocationID、经度,纬度坐标或城市名称
+        location: String,
+        /// 预报小时数，24、72或168
+        #[arg(long, default_value_t = 24)]
+        hours: u8,
+    },
+    /// 格点天气，默认为实时天气
+    Grid {
+        /// 经度,纬度坐标或城市名称
+        location: String,
+        /// 每日预报天数，3或7
+        #[arg(long, conflicts_with = "hours")]
+        days: Option<i32>,
+        /// 逐小时预报小时数，24或72
+        #[arg(long)]
+        hours: Option<i32>,
+    },
+    /// 分钟级降水
+    Minutely {
+        /// 经度,纬度坐标或城市名称
+        location: String,
+    },
+    /// 天气灾害预警
+    Warning {
+        /// LocationID、经度,纬度坐标或城市名称
+        location: String,
+    },
+    /// 天气指数预报
+    Indices {
+        /// LocationID、经度,纬度坐标或城市名称
+        location: String,
+        /// 指数类型，多个类型用英文逗号分隔，0 为全部
+        #[arg(long = "type", default_value = "0")]
+        type_: String,
+        /// 预报天数，1或3
+        #[arg(long, default_value_t = 1)]
+        days: i32,
+    },
+    /// 空气质量，默认为实时空气质量
+    Air {
+        /// 经度,纬度坐标、LocationID或城市名称
+        location: String,
+        /// 空气质量小时预报
+        #[arg(long, conflicts_with = "daily")]
+        hourly: bool,
+        /// 空气质量每日预报
+        #[arg(long)]
+        daily: bool,
+    },
+    /// 台风预报
+    Storm {
+        /// 台风ID
+        storm_id: String,
+    },
+    /// 城市和POI搜索
+    Geo {
+        #[command(subcommand)]
+        command: GeoCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum GeoCommand {
+    /// 城市搜索
+    City {
+        /// 城市名称、经度,纬度坐标或LocationID
+        location: String,
+        /// 上级行政区划
+        #[arg(long)]
+        adm: Option<String>,
+        /// 搜索范围，ISO 3166 国家代码，例如 cn
+        #[arg(long)]
+        range: Option<String>,
+        /// 返回结果的数量，1-20
+        #[arg(long)]
+        number: Option<u32>,
+    },
+    /// 热门城市
+    Top {
+        /// 搜索范围，ISO 3166 国家代码，例如 cn
+        #[arg(long)]
+        range: Option<String>,
+        /// 返回结果的数量，1-20
+        #[arg(long)]
+        number: Option<i32>,
+    },
+    /// POI搜索
+    Poi {
+        /// POI名称、经度,纬度坐标或LocationID
+        location: String,
+        /// POI类型，scenic、CSTA 或 TSTA
+        #[arg(long = "type", default_value = "scenic")]
+        type_: String,
+        /// 搜索的城市
+        #[arg(long)]
+        city: Option<String>,
+        /// 返回结果的数量，1-20
+        #[arg(long)]
+        number: Option<u32>,
+    },
+    /// POI范围搜索
+    Range {
+        /// 经度,纬度坐标或城市名称
+        location: String,
+        /// POI类型，scenic、CSTA 或 TSTA
+        #[arg(long = "type", default_value = "scenic")]
+        type_: String,
+        /// 搜索半径，1-50公里
+        #[arg(long)]
+        radius: Option<f32>,
+        /// 返回结果的数量，1-20
+        #[arg(long)]
+        number: Option<u32>,
+    },
+}
+
+const NOW_COLUMNS: &[&str] = &[
+    "obsTime",
+    "temp",
+    "feelsLike",
+    "text",
+    "windDir",
+    "windScale",
+    "humidity",
+    "precip",
+    "pressure",
+    "vis",
+];
+const DAILY_COLUMNS: &[&str] = &[
+    "fxDate",
+    "tempMin",
+    "tempMax",
+    "textDay",
+    "textNight",
+    "windDirDay",
+    "windScaleDay",
+    "humidity",
+    "precip",
+    "uvIndex",
+];
+const HOURLY_COLUMNS: &[&str] = &[
+    "fxTime",
+    "temp",
+    "text",
+    "windDir",
+    "windScale",
+    "humidity",
+    "pop",
+    "precip",
+];
+const GRID_DAILY_COLUMNS: &[&str] = &[
+    "fxDate",
+    "tempMin",
+    "tempMax",
+    "textDay",
+    "textNight",
+    "windDirDay",
+    "windScaleDay",
+    "humidity",
+    "precip",
+];
+const MINUTELY_COLUMNS: &[&str] = &["fxTime", "precip", "type"];
+const WARNING_COLUMNS: &[&str] = &[
+    "pubTime",
+    "title",
+    "severity",
+    "severityColor",
+    "typeName",
+    "startTime",
+    "endTime",
+];
+const INDICES_COLUMNS: &[&str] = &["date", "name", "level", "category", "text"];
+const AQI_COLUMNS: &[&str] = &[
+    "time",
+    "name",
+    "aqiDisplay",
+    "level",
+    "category",
+    "primaryPollutant.name",
+];
+const STORM_COLUMNS: &[&str] = &[
+    "fxTime",
+    "lat",
+    "lon",
+    "type",
+    "pressure",
+    "windSpeed",
+    "moveSpeed",
+    "moveDir",
+];
+const LOCATION_COLUMNS: &[&str] = &["id", "name", "adm2", "adm1", "country", "lat", "lon", "tz"];
+const POI_COLUMNS: &[&str] = &[
+    "id", "name", "type", "adm2", "adm1", "country", "lat", "lon",
+];
+
+/// 命令的结果
+#[derive(Debug)]
+struct Output {
+    /// 完整的响应
+    json: Value,
+    /// 表格和CSV中的行
+    rows: Vec<Value>,
+    /// 表格和CSV中的列，`.` 分隔嵌套的字段
+    columns: &'static [&'static str],
+}
+
+impl Output {
+    /// `rows` 为响应中数组或对象字段的名称
+    fn new(resp: impl Serialize, rows: &str, columns: &'static [&'static str]) -> Self {
+        let json = serde_json::to_value(resp).expect("response is serializable");
+        let rows = match &json[rows] {
+            Value::Array(rows) => rows.clone(),
+            Value::Null => vec![],
+            row => vec![row.clone()],
+        };
+        Output {
+            json,
+            rows,
+            columns,
+        }
+    }
+
+    /// 空气质量的每个指数一行，`time` 为预报时间
+    fn air_quality(resp: impl Serialize, items: Option<(&str, &str)>) -> Self {
+        let json = serde_json::to_value(resp).expect("response is serializable");
+        let with_time = |item: &Value, time: &Value| {
+            let time = time.clone();
+            let indexes = item["indexes"].as_array().cloned().unwrap_or_default();
+            indexes.into_iter().map(move |mut index| {
+                if let Value::Object(index) = &mut index {
+                    index.insert("time".to_string(), time.clone());
+                }
+                index
+            })
+        };
+        let rows = match items {
+            Some((items, time)) => json[items]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .flat_map(|item| with_time(item, &item[time]))
+                .collect(),
+            None => with_time(&json, &Value::Null).collect(),
+        };
+        Output {
+            json,
+            rows,
+            columns: AQI_COLUMNS,
+        }
+    }
+
+    fn render(&self, format: Format) -> String {
+        match format {
+            Format::Json => {
+                serde_json::to_string_pretty(&self.json).expect("JSON is serializable") + "\n"
+            }
+            Format::Table => table(self.columns, &self.cells()),
+            Format::Csv => csv(self.columns, &self.cells()),
+        }
+    }
+
+    fn cells(&self) -> Vec<Vec<String>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .map(|column| {
+                        let value = column
+                            .split('.')
+                            .try_fold(row, |value, key| value.get(key))
+                            .unwrap_or(&Value::Null);
+                        cell(value)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// 终端中的显示宽度，中日韩文字和全角字符占两列
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6 => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+fn table(columns: &[&str], rows: &[Vec<String>]) -> String {
+    let widths: Vec<_> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, column)| {
+            rows.iter()
+                .map(|row| display_width(&row[i]))
+                .chain([display_width(column)])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+    let line = |cells: Vec<&str>| {
+        let mut line = String::new();
+        for (i, cell) in cells.iter().enumerate() {
+            if i > 0 {
+                line.push_str("  ");
+            }
+            line.push_str(cell);
+            if i + 1 < cells.len() {
+                line.push_str(&" ".repeat(widths[i] - display_width(cell)));
+            }
+        }
+        line.truncate(line.trim_end().len());
+        line.push('\n');
+        line
+    };
+
+    let mut table = line(columns.to_vec());
+    for row in rows {
+        table.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    table
+}
+
+fn csv(columns: &[&str], rows: &[Vec<String>]) -> String {
+    let field = |s: &str| {
+        if s.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", s.replace('"', "\"\""))
+        } else {
+            s.to_string()
+        }
+    };
+    let line = |cells: Vec<&str>| {
+        let mut line = cells.into_iter().map(field).collect::<Vec<_>>().join(",");
+        line.push('\n');
+        line
+    };
+
+    let mut csv = line(columns.to_vec());
+    for row in rows {
+        csv.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    csv
+}
+
+/// 成功的响应，其他情况转换为错误信息
+fn success<T>(result: APIResult<T>) -> Result<T, String> {
+    match result {
+        Ok(APIResponse::Success(resp)) => Ok(resp),
+        Ok(APIResponse::Error(code)) => Err(format!("request failed: {}", code)),
+        Ok(APIResponse::ParseError { error, .. }) => {
+            Err(format!("failed to parse response: {}", error))
+        }
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 解析 `经度,纬度` 坐标
+fn coordinates(location: &str) -> Option<(f64, f64)> {
+    let (lon, lat) = location.split_once(',')?;
+    Some((lon.trim().parse().ok()?, lat.trim().parse().ok()?))
+}
+
+/// LocationID 均为数字
+fn is_location_id(location: &str) -> bool {
+    !location.is_empty() && location.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// 通过城市搜索查找地点，取第一个结果
+async fn lookup(client: &QWeatherClient, location: &str) -> Result<Location, String> {
+    let resp = success(
+        client
+            .geo_city_lookup(CityLookupInput {
+                location,
+                number: Some(1),
+                ..Default::default()
+            })
+            .await,
+    )?;
+    resp.location
+        .into_iter()
+        .next()
+        .ok_or_else(|| format!("location not found: {}", location))
+}
+
+/// 可以直接用于天气接口的地点，城市名称转换为LocationID
+async fn resolve_location(client: &QWeatherClient, location: &str) -> Result<String, String> {
+    if is_location_id(location) || coordinates(location).is_some() {
+        return Ok(location.to_string());
+    }
+    Ok(lookup(client, location).await?.id)
+}
+
+/// 地点的经度和纬度，LocationID和城市名称通过城市搜索转换
+async fn resolve_coordinates(
+    client: &QWeatherClient,
+    location: &str,
+) -> Result<(f64, f64), String> {
+    if let Some(coordinates) = coordinates(location) {
+        return Ok(coordinates);
+    }
+    let location = lookup(client, location).await?;
+    Ok((location.lon, location.lat))
+}
+
+/// 接口要求的 `经度,纬度` 格式，最多两位小数
+fn format_coordinates((lon, lat): (f64, f64)) -> String {
+    format!("{:.2},{:.2}", lon, lat)
+}
+
+async fn run(client: &QWeatherClient, command: Command) -> Result<Output, String> {
+    let output = match command {
+        Command::Now { location } => {
+            let location = resolve_location(client, &location).await?;
+            let resp = success(client.weather_now(&location).await)?;
+            Output::new(resp, "now", NOW_COLUMNS)
+        }
+        Command::Daily { location, days } => {
+            let location = resolve_location(client, &location).await?;
+            let resp = success(client.weather_daily_forecast(&location, days).await)?;
+            Output::new(resp, "daily", DAILY_COLUMNS)
+        }
+        Command::Hourly { location, hours } => {
+            let location = resolve_location(client, &location).await?;
+            let resp = success(client.weather_hourly_forecast(&location, hours).await)?;
+            Output::new(resp, "hourly", HOURLY_COLUMNS)
+        }
+        Command::Grid {
+            location,
+            days,
+            hours,
+        } => {
+            let location = format_coordinates(resolve_coordinates(client, &location).await?);
+            match (days, hours) {
+                (Some(days), _) => {
+                    let resp = success(client.grid_weather_daily_forecast(&location, days).await)?;
+                    Output::new(resp, "daily", GRID_DAILY_COLUMNS)
+                }
+                (None, Some(hours)) => {
+                    let resp =
+                        success(client.grid_weather_hourly_forecast(&location, hours).await)?;
+                    Output::new(resp, "hourly", HOURLY_COLUMNS)
+                }
+                (None, None) => {
+                    let resp = success(client.grid_weather_now(&location).await)?;
+                    Output::new(resp, "now", NOW_COLUMNS)
+                }
+            }
+        }
+        Command::Minutely { location } => {
+            let location = format_coordinates(resolve_coordinates(client, &location).await?);
+            let resp = success(client.minutely_precipitation(&location).await)?;
+            Output::new(resp, "minutely", MINUTELY_COLUMNS)
+        }
+        Command::Warning { location } => {
+            let location = resolve_location(client, &location).await?;
+            let resp = success(client.weather_warning(&location).await)?;
+            Output::new(resp, "warning", WARNING_COLUMNS)
+        }
+        Command::Indices {
+            location,
+            type_,
+            days,
+        } => {
+            let location = resolve_location(client, &location).await?;
+            let resp = success(client.indices_forecast(&location, &type_, days).await)?;
+            Output::new(resp, "daily", INDICES_COLUMNS)
+        }
+        Command::Air {
+            location,
+            hourly,
+            daily,
+        } => {
+            let (lon, lat) = resolve_coordinates(client, &location).await?;
+            if hourly {
+                let resp = success(client.air_hourly_forecast(lat, lon).await)?;
+                Output::air_quality(resp, Some(("hours", "forecastTime")))
+            } else if daily {
+                let resp = success(client.air_daily_forecast(lat, lon).await)?;
+                Output::air_quality(resp, Some(("days", "forecastStartTime")))
+            } else {
+                let resp = success(client.air_current(lat, lon).await)?;
+                Output::air_quality(resp, None)
+            }
+        }
+        Command::Storm { storm_id } => {
+            let resp = success(client.storm_forecast(&storm_id).await)?;
+            Output::new(resp, "forecast", STORM_COLUMNS)
+        }
+        Command::Geo { command } => match command {
+            GeoCommand::City {
+                location,
+                adm,
+                range,
+                number,
+            } => {
+                let resp = success(
+                    client
+                        .geo_city_lookup(CityLookupInput {
+                            location: &location,
+                            adm: adm.as_deref(),
+                            range: range.as_deref(),
+                            number,
+                        })
+                        .await,
+                )?;
+                Output::new(resp, "location", LOCATION_COLUMNS)
+            }
+            GeoCommand::Top { range, number } => {
+                let resp = success(client.geo_city_top(range.as_deref(), number).await)?;
+                Output::new(resp, "topCityList", LOCATION_COLUMNS)
+            }
+            GeoCommand::Poi {
+                location,
+                type_,
+                city,
+                number,
+            } => {
+                let resp = success(
+                    client
+                        .geo_poi_lookup(GeoPoiLookupInput {
+                            location: &location,
+                            type_: &type_,
+                            city: city.as_deref(),
+                            number,
+                        })
+                        .await,
+                )?;
+                Output::new(resp, "poi", POI_COLUMNS)
+            }
+            GeoCommand::Range {
+                location,
+                type_,
+                radius,
+                number,
+            } => {
+                let location = format_coordinates(resolve_coordinates(client, &location).await?);
+                let resp = success(
+                    client
+                        .geo_poi_range(GeoPoiRangeInput {
+                            location: &location,
+                            type_: &type_,
+                            radius,
+                            number,
+                        })
+                        .await,
+                )?;
+                Output::new(resp, "poi", POI_COLUMNS)
+            }
+        },
+    };
+
+    Ok(output)
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let _ = dotenvy::dotenv();
+    let (Ok(id), Ok(key)) = (env::var("QWEATHER_ID"), env::var("QWEATHER_KEY")) else {
+        eprintln!("error: QWEATHER_ID and QWEATHER_KEY must be set in the environment or .env");
+        return ExitCode::from(2);
+    };
+
+    let mut config = ClientConfig::new(id, key);
+    if let Ok(api_host) = env::var("QWEATHER_API_HOST") {
+        config.api_host = Some(api_host);
+    }
+    let client = match QWeatherClient::try_with_config(cli.configure(config)) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run(&client, cli.command).await {
+        Ok(output) => {
+            print!("{}", output.render(cli.output));
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[test]
+fn test_render() {
+    let output = Output::new(
+        serde_json::json!({
+            "code": "200",
+            "daily": [
+                { "fxDate": "2021-11-15", "textDay": "多云", "wind": { "dir": "N" } },
+                { "fxDate": "2021-11-16", "textDay": "Sunny, \"hot\"", "wind": null }
+            ]
+        }),
+        "daily",
+        &["fxDate", "textDay", "wind.dir"],
+    );
+
+    assert_eq!(
+        output.render(Format::Table),
+        "fxDate      textDay       wind.dir\n\
+         2021-11-15  多云          N\n\
+         2021-11-16  Sunny, \"hot\"\n"
+    );
+    assert_eq!(
+        output.render(Format::Csv),
+        "fxDate,textDay,wind.dir\n\
+         2021-11-15,多云,N\n\
+         2021-11-16,\"Sunny, \"\"hot\"\"\",\n"
+    );
+    let json: Value = serde_json::from_str(&output.render(Format::Json)).unwrap();
+    assert_eq!(json["daily"][0]["textDay"], "多云");
+}
+
+#[test]
+fn test_location_kind() {
+    assert_eq!(coordinates("116.41,39.92"), Some((116.41, 39.92)));
+    assert_eq!(coordinates("北京"), None);
+    assert!(is_location_id("101010100"));
+    assert!(!is_location_id("beijing"));
+    assert_eq!(format_coordinates((116.40529, 39.90499)), "116.41,39.90");
+    assert!(
+        Cli::try_parse_from(["qweather", "grid", "北京", "--days", "3", "--hours", "24"]).is_err()
+    );
+}
+
+#[cfg(all(test, feature = "testing"))]
+#[tokio::test]
+async fn test_run_resolves_city_names() {
+    use qweather_sdk::testing::FakeServer;
+
+    let server = FakeServer::start().await.unwrap();
+    let client = server.client();
+
+    let output = run(
+        &client,
+        Command::Now {
+            location: "北京".to_string(),
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(output.rows.len(), 1);
+    let requests = server.requests();
+    assert_eq!(requests[0].endpoint, Some("geo_city_lookup"));
+    assert_eq!(requests[1].params["location"], "101010100");
+
+    let output = run(
+        &client,
+        Command::Air {
+            location: "101010100".to_string(),
+            hourly: false,
+            daily: false,
+        },
+    )
+    .await
+    .unwrap();
+    assert!(output.render(Format::Table).starts_with("time"));
+    assert_eq!(
+        server.requests().last().unwrap().path,
+        "/airquality/v1/current/39.90499/116.40529"
+    );
+
+    server.fail_with("weather_warning", "403");
+    let error = run(
+        &client,
+        Command::Warning {
+            location: "101010100".to_string(),
+        },
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error, "request failed: 403");
+}
+
+#[cfg(all(test, feature = "testing"))]
+#[tokio::test]
+async fn test_run_with_unit_and_lang() {
+    use qweather_sdk::testing::FakeServer;
+
+    let server = FakeServer::start().await.unwrap();
+    let cli = Cli::try_parse_from([
+        "qweather",
+        "now",
+        "101010100",
+        "--unit",
+        "i",
+        "--lang",
+        "en",
+    ])
+    .unwrap();
+    let client = QWeatherClient::with_config(cli.configure(server.config()));
+
+    run(&client, cli.command).await.unwrap();
+    let requests = server.requests();
+    assert_eq!(requests[0].params["unit"], "i");
+    assert_eq!(requests[0].params["lang"], "en");
+}
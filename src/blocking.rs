@@ -0,0 +1,428 @@
+//! 同步客户端
+//!
+//! 需要开启 `blocking` feature。[`QWeatherClient`] 与异步客户端的方法一一对应，共用相同的数据结构和签名逻辑，
+//! 内部使用单线程的 tokio 运行时执行请求，调用方无需自行创建运行时。
+//!
+//! 与 `reqwest::blocking` 相同，不能在异步运行时中调用。
+//!
+//! ```no_run
+//! use qweather_sdk::{blocking::QWeatherClient, client::ClientConfig};
+//!
+//! let client = QWeatherClient::with_config(ClientConfig::new("id", "key"));
+//! let resp = client.weather_now("101010100").unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    api::{
+        air_quality::{
+            AirCurrentResponse, AirDailyForecastResponse, AirHourlyForecastResponse,
+            AirStationResponse,
+        },
+        geo::{
+            CityLookupInput, CityLookupResponse, GeoPoiLookupInput, GeoPoiRangeInput, POIResponse,
+            TopCityResponse,
+        },
+        grid_weather::{
+            GridWeatherDailyForecastResponse, GridWeatherHourlyForecastResponse,
+            GridWeatherNowResponse,
+        },
+        indices::IndicesForecastResponse,
+        minutely::MinutePrecipitationResponse,
+        tropical_cyclone::StormForecastResponse,
+        warning::{WeatherWarningCityListResponse, WeatherWarningResponse},
+        weather::{
+            WeatherDailyForecastResponse, WeatherHourlyForecastResponse, WeatherNowResponse,
+        },
+        APIResponse, RawResponse,
+    },
+    cache::ResponseCache,
+    client::{self, ClientConfig},
+    key_pool::KeyPool,
+    rate_limit::RateLimiter,
+    signer::Signer,
+    transport::Transport,
+    APIResult, Error,
+};
+
+/// 同步天气API客户端
+pub struct QWeatherClient {
+    inner: client::QWeatherClient,
+    runtime: Runtime,
+}
+
+/// 生成与异步客户端同名的同步方法
+macro_rules! blocking_methods {
+    ($($(#[$doc:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(&self $(, $arg: $ty)*) -> APIResult<$ret> {
+                self.runtime.block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl QWeatherClient {
+    /// 使用配置创建新的客户端
+    ///
+    /// # Panics
+    ///
+    /// 无法创建 reqwest 客户端或运行时时 panic，需要处理该错误时请使用 [`QWeatherClient::try_with_config`]
+    pub fn with_config(client_config: ClientConfig) -> Self {
+        Self::try_with_config(client_config).expect("Failed to create blocking client")
+    }
+
+    /// 使用配置创建新的客户端，无法创建 reqwest 客户端或运行时时返回错误
+    pub fn try_with_config(client_config: ClientConfig) -> Result<Self, Error> {
+        Self::try_from_async(client::QWeatherClient::try_with_config(client_config)?)
+    }
+
+    /// 创建新的客户端，参见 [`client::QWeatherClient::new`]
+    ///
+    /// # Panics
+    ///
+    /// 无法创建 reqwest 客户端或运行时时 panic
+    pub fn new(
+        public_id: impl ToString,
+        private_key: impl ToString,
+        subscription: bool,
+        lang: impl ToString,
+        unit: impl ToString,
+    ) -> Self {
+        Self::from_async(client::QWeatherClient::new(
+            public_id,
+            private_key,
+            subscription,
+            lang,
+            unit,
+        ))
+    }
+
+    /// 使用配置和已有的 reqwest 客户端创建新的客户端
+    ///
+    /// # Panics
+    ///
+    /// 无法创建运行时时 panic，需要处理该错误时请使用 [`QWeatherClient::try_with_http_client`]
+    pub fn with_http_client(client_config: ClientConfig, client: Client) -> Self {
+        Self::from_async(client::QWeatherClient::with_http_client(
+            client_config,
+            client,
+        ))
+    }
+
+    /// 使用配置和已有的 reqwest 客户端创建新的客户端，无法创建运行时时返回错误
+    pub fn try_with_http_client(
+        client_config: ClientConfig,
+        client: Client,
+    ) -> Result<Self, Error> {
+        Self::try_from_async(client::QWeatherClient::with_http_client(
+            client_config,
+            client,
+        ))
+    }
+
+    /// 使用配置和自定义传输创建新的客户端
+    ///
+    /// # Panics
+    ///
+    /// 无法创建运行时时 panic，需要处理该错误时请使用 [`QWeatherClient::try_with_transport`]
+    pub fn with_transport(
+        client_config: ClientConfig,
+        transport: impl Transport + 'static,
+    ) -> Self {
+        Self::from_async(client::QWeatherClient::with_transport(
+            client_config,
+            transport,
+        ))
+    }
+
+    /// 使用配置和自定义传输创建新的客户端，无法创建运行时时返回错误
+    pub fn try_with_transport(
+        client_config: ClientConfig,
+        transport: impl Transport + 'static,
+    ) -> Result<Self, Error> {
+        Self::try_from_async(client::QWeatherClient::with_transport(
+            client_config,
+            transport,
+        ))
+    }
+
+    /// 包装已有的异步客户端
+    ///
+    /// # Panics
+    ///
+    /// 无法创建运行时时 panic，需要处理该错误时请使用 [`QWeatherClient::try_from_async`]
+    pub fn from_async(inner: client::QWeatherClient) -> Self {
+        Self::try_from_async(inner).expect("Failed to create blocking client")
+    }
+
+    /// 包装已有的异步客户端，无法创建运行时时返回错误
+    pub fn try_from_async(inner: client::QWeatherClient) -> Result<Self, Error> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| Error::Transport(e.to_string()))?;
+
+        Ok(QWeatherClient { inner, runtime })
+    }
+
+    /// 获取内部的异步客户端
+    pub fn as_async(&self) -> &client::QWeatherClient {
+        &self.inner
+    }
+
+    /// 获取API Host
+    pub fn get_api_host(&self) -> &str {
+        self.inner.get_api_host()
+    }
+
+    /// 获取GEO API Host
+    pub fn get_geo_api_host(&self) -> &str {
+        self.inner.get_geo_api_host()
+    }
+
+    /// 获取限流器，可用于查询剩余的每日请求额度
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.inner.rate_limiter()
+    }
+
+    /// 获取请求签名器，可用于生成签名URL
+    pub fn signer(&self) -> &Signer {
+        self.inner.signer()
+    }
+
+    /// 签名用的当前时间，参见 [`client::QWeatherClient::now`]
+    pub fn now(&self) -> DateTime<Utc> {
+        self.inner.now()
+    }
+
+    /// 获取多密钥池，可用于查询各密钥的统计
+    pub fn key_pool(&self) -> Option<&KeyPool> {
+        self.inner.key_pool()
+    }
+
+    /// 获取响应缓存，可用于查询命中统计
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.inner.cache()
+    }
+
+    /// 请求API
+    pub fn request_api<T>(
+        &self,
+        url: String,
+        params: BTreeMap<String, String>,
+    ) -> Result<APIResponse<T>, Error>
+    where
+        T: serde::de::DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        self.runtime.block_on(self.inner.request_api(url, params))
+    }
+
+    /// 请求API，返回未解析的原始响应
+    pub fn request_raw(
+        &self,
+        url: String,
+        params: BTreeMap<String, String>,
+    ) -> Result<APIResponse<RawResponse>, Error> {
+        self.runtime.block_on(self.inner.request_raw(url, params))
+    }
+
+    blocking_methods! {
+        /// 实时空气质量(new)，参见 [`client::QWeatherClient::air_current`]
+        fn air_current(&self, latitude: f64, longitude: f64) -> AirCurrentResponse;
+        /// 空气质量小时预报(new)，参见 [`client::QWeatherClient::air_hourly_forecast`]
+        fn air_hourly_forecast(&self, latitude: f64, longitude: f64) -> AirHourlyForecastResponse;
+        /// 空气质量每日预报(new)，参见 [`client::QWeatherClient::air_daily_forecast`]
+        fn air_daily_forecast(&self, latitude: f64, longitude: f64) -> AirDailyForecastResponse;
+        /// 监测站数据(new)，参见 [`client::QWeatherClient::air_station`]
+        fn air_station(&self, location_id: &str) -> AirStationResponse;
+        /// 城市搜索，参见 [`client::QWeatherClient::geo_city_lookup`]
+        fn geo_city_lookup(&self, city_look_up_input: CityLookupInput<'_>) -> CityLookupResponse;
+        /// 热门城市查询，参见 [`client::QWeatherClient::geo_city_top`]
+        fn geo_city_top(&self, range: Option<&str>, number: Option<i32>) -> TopCityResponse;
+        /// POI搜索，参见 [`client::QWeatherClient::geo_poi_lookup`]
+        fn geo_poi_lookup(&self, geo_poi_lookup_input: GeoPoiLookupInput<'_>) -> POIResponse;
+        /// POI范围搜索，参见 [`client::QWeatherClient::geo_poi_range`]
+        fn geo_poi_range(&self, geo_poi_range_input: GeoPoiRangeInput<'_>) -> POIResponse;
+        /// 格点实时天气，参见 [`client::QWeatherClient::grid_weather_now`]
+        fn grid_weather_now(&self, location: &str) -> GridWeatherNowResponse;
+        /// 格点每日天气预报，参见 [`client::QWeatherClient::grid_weather_daily_forecast`]
+        fn grid_weather_daily_forecast(&self, location: &str, day: i32) -> GridWeatherDailyForecastResponse;
+        /// 格点逐小时天气预报，参见 [`client::QWeatherClient::grid_weather_hourly_forecast`]
+        fn grid_weather_hourly_forecast(&self, location: &str, hour: i32) -> GridWeatherHourlyForecastResponse;
+        /// 天气指数预报，参见 [`client::QWeatherClient::indices_forecast`]
+        fn indices_forecast(&self, location: &str, type_: &str, day: i32) -> IndicesForecastResponse;
+        /// 分钟级降水，参见 [`client::QWeatherClient::minutely_precipitation`]
+        fn minutely_precipitation(&self, location: &str) -> MinutePrecipitationResponse;
+        /// 台风预报，参见 [`client::QWeatherClient::storm_forecast`]
+        fn storm_forecast(&self, storm_id: &str) -> StormForecastResponse;
+        /// 天气灾害预警，参见 [`client::QWeatherClient::weather_warning`]
+        fn weather_warning(&self, location: &str) -> WeatherWarningResponse;
+        /// 天气预警城市列表，参见 [`client::QWeatherClient::weather_warning_city_list`]
+        fn weather_warning_city_list(&self, range: &str) -> WeatherWarningCityListResponse;
+        /// 实时天气，参见 [`client::QWeatherClient::weather_now`]
+        fn weather_now(&self, location: &str) -> WeatherNowResponse;
+        /// 每日天气预报，参见 [`client::QWeatherClient::weather_daily_forecast`]
+        fn weather_daily_forecast(&self, location: &str, day: u8) -> WeatherDailyForecastResponse;
+        /// 逐小时天气预报，参见 [`client::QWeatherClient::weather_hourly_forecast`]
+        fn weather_hourly_forecast(&self, location: &str, hour: u8) -> WeatherHourlyForecastResponse;
+        /// 实时空气质量(new)的原始响应，参见 [`client::QWeatherClient::air_current_raw`]
+        fn air_current_raw(&self, latitude: f64, longitude: f64) -> RawResponse;
+        /// 空气质量小时预报(new)的原始响应，参见 [`client::QWeatherClient::air_hourly_forecast_raw`]
+        fn air_hourly_forecast_raw(&self, latitude: f64, longitude: f64) -> RawResponse;
+        /// 空气质量每日预报(new)的原始响应，参见 [`client::QWeatherClient::air_daily_forecast_raw`]
+        fn air_daily_forecast_raw(&self, latitude: f64, longitude: f64) -> RawResponse;
+        /// 监测站数据(new)的原始响应，参见 [`client::QWeatherClient::air_station_raw`]
+        fn air_station_raw(&self, location_id: &str) -> RawResponse;
+        /// 城市搜索的原始响应，参见 [`client::QWeatherClient::geo_city_lookup_raw`]
+        fn geo_city_lookup_raw(&self, city_look_up_input: CityLookupInput<'_>) -> RawResponse;
+        /// 热门城市查询的原始响应，参见 [`client::QWeatherClient::geo_city_top_raw`]
+        fn geo_city_top_raw(&self, range: Option<&str>, number: Option<i32>) -> RawResponse;
+        /// POI搜索的原始响应，参见 [`client::QWeatherClient::geo_poi_lookup_raw`]
+        fn geo_poi_lookup_raw(&self, geo_poi_lookup_input: GeoPoiLookupInput<'_>) -> RawResponse;
+        /// POI范围搜索的原始响应，参见 [`client::QWeatherClient::geo_poi_range_raw`]
+        fn geo_poi_range_raw(&self, geo_poi_range_input: GeoPoiRangeInput<'_>) -> RawResponse;
+        /// 格点实时天气的原始响应，参见 [`client::QWeatherClient::grid_weather_now_raw`]
+        fn grid_weather_now_raw(&self, location: &str) -> RawResponse;
+        /// 格点每日天气预报的原始响应，参见 [`client::QWeatherClient::grid_weather_daily_forecast_raw`]
+        fn grid_weather_daily_forecast_raw(&self, location: &str, day: i32) -> RawResponse;
+        /// 格点逐小时天气预报的原始响应，参见 [`client::QWeatherClient::grid_weather_hourly_forecast_raw`]
+        fn grid_weather_hourly_forecast_raw(&self, location: &str, hour: i32) -> RawResponse;
+        /// 天气指数预报的原始响应，参见 [`client::QWeatherClient::indices_forecast_raw`]
+        fn indices_forecast_raw(&self, location: &str, type_: &str, day: i32) -> RawResponse;
+        /// 分钟级降水的原始响应，参见 [`client::QWeatherClient::minutely_precipitation_raw`]
+        fn minutely_precipitation_raw(&self, location: &str) -> RawResponse;
+        /// 台风预报的原始响应，参见 [`client::QWeatherClient::storm_forecast_raw`]
+        fn storm_forecast_raw(&self, storm_id: &str) -> RawResponse;
+        /// 天气灾害预警的原始响应，参见 [`client::QWeatherClient::weather_warning_raw`]
+        fn weather_warning_raw(&self, location: &str) -> RawResponse;
+        /// 天气预警城市列表的原始响应，参见 [`client::QWeatherClient::weather_warning_city_list_raw`]
+        fn weather_warning_city_list_raw(&self, range: &str) -> RawResponse;
+        /// 实时天气的原始响应，参见 [`client::QWeatherClient::weather_now_raw`]
+        fn weather_now_raw(&self, location: &str) -> RawResponse;
+        /// 每日天气预报的原始响应，参见 [`client::QWeatherClient::weather_daily_forecast_raw`]
+        fn weather_daily_forecast_raw(&self, location: &str, day: u8) -> RawResponse;
+        /// 逐小时天气预报的原始响应，参见 [`client::QWeatherClient::weather_hourly_forecast_raw`]
+        fn weather_hourly_forecast_raw(&self, location: &str, hour: u8) -> RawResponse;
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+#[test]
+fn test_blocking_parity() {
+    use crate::testing::FakeServer;
+
+    // 模拟服务运行在单独的多线程运行时上，不阻塞同步客户端
+    let server_runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+        .unwrap();
+    let server = server_runtime.block_on(FakeServer::start()).unwrap();
+
+    let mut config = server.config();
+    config.cache = Some(ResponseCache::memory(10));
+    config.rate_limiter = Some(RateLimiter::new().with_daily_budget(100));
+    let blocking = QWeatherClient::with_config(config);
+    let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+    let async_client = client::QWeatherClient::with_config(server.config());
+
+    let to_json = |resp: APIResponse<serde_json::Value>| match resp {
+        APIResponse::Success(value) => value,
+        APIResponse::Error(code) => serde_json::json!({ "error": code }),
+        APIResponse::ParseError { error, .. } => serde_json::json!({ "error": error }),
+    };
+
+    let sync_now = blocking.weather_now("101010100").unwrap();
+    let async_now = runtime
+        .block_on(async_client.weather_now("101010100"))
+        .unwrap();
+    match (sync_now, async_now) {
+        (APIResponse::Success(sync_now), APIResponse::Success(async_now)) => assert_eq!(
+            serde_json::to_value(sync_now).unwrap(),
+            serde_json::to_value(async_now).unwrap()
+        ),
+        _ => panic!("expected success"),
+    }
+
+    let sync_top = blocking.geo_city_top(Some("cn"), Some(1)).unwrap();
+    let async_top = runtime
+        .block_on(async_client.geo_city_top(Some("cn"), Some(1)))
+        .unwrap();
+    match (sync_top, async_top) {
+        (APIResponse::Success(sync_top), APIResponse::Success(async_top)) => {
+            assert_eq!(sync_top.top_city_list[0].id, "101010100");
+            assert_eq!(
+                serde_json::to_value(sync_top).unwrap(),
+                serde_json::to_value(async_top).unwrap()
+            )
+        }
+        _ => panic!("expected success"),
+    }
+
+    // 缓存、额度和签名的状态与异步客户端相同
+    assert!(matches!(
+        blocking.weather_now("101010100").unwrap(),
+        APIResponse::Success(_)
+    ));
+    let stats = blocking.cache().unwrap().stats();
+    assert_eq!((stats.hits, stats.misses), (1, 2));
+    assert_eq!(blocking.rate_limiter().unwrap().remaining(), Some(98));
+    assert_eq!(blocking.signer().public_id(), server.config().public_id);
+    assert!(blocking.key_pool().is_none());
+    assert!((blocking.now() - Utc::now()).num_seconds().abs() < 5);
+
+    let url = format!("{}/v7/unknown", server.url());
+    let sync_error = blocking.request_api(url.clone(), BTreeMap::new()).unwrap();
+    let async_error = runtime
+        .block_on(async_client.request_api(url, BTreeMap::new()))
+        .unwrap();
+    assert_eq!(to_json(sync_error), to_json(async_error));
+
+    // 两个客户端发出的请求除时间戳和签名外完全相同
+    let requests = server.requests();
+    assert_eq!(requests.len(), 6);
+    for pair in requests.chunks(2) {
+        let strip = |params: &BTreeMap<String, String>| {
+            let mut params = params.clone();
+            params.remove("t");
+            params.remove("sign");
+            params
+        };
+        assert_eq!(pair[0].path, pair[1].path);
+        assert_eq!(strip(&pair[0].params), strip(&pair[1].params));
+        assert!(pair[0].params.contains_key("sign"));
+    }
+}
+
+#[test]
+fn test_blocking_constructors() {
+    use crate::transport::MockTransport;
+
+    // 与异步客户端的同名构造函数签名相同
+    let transport = MockTransport::new();
+    transport.push_json(serde_json::json!({ "code": "401" }));
+    let blocking: QWeatherClient =
+        QWeatherClient::with_transport(ClientConfig::new("id", "key"), transport.clone());
+    assert!(matches!(
+        blocking.weather_now("101010100").unwrap(),
+        APIResponse::Error(code) if code == "401"
+    ));
+    assert_eq!(transport.requests().len(), 1);
+
+    let blocking: QWeatherClient =
+        QWeatherClient::with_http_client(ClientConfig::new("id", "key"), Client::new());
+    assert_eq!(blocking.signer().public_id(), "id");
+    let blocking =
+        QWeatherClient::try_with_transport(ClientConfig::new("id", "key"), transport).unwrap();
+    assert_eq!(blocking.get_api_host(), blocking.as_async().get_api_host());
+    assert!(
+        QWeatherClient::try_with_http_client(ClientConfig::new("id", "key"), Client::new()).is_ok()
+    );
+}
@@ -0,0 +1,362 @@
+//! 响应缓存
+//!
+//! 和风天气的数据通常每隔一段时间才更新一次，[`ResponseCache`] 在有效期内直接返回已缓存的响应，
+//! 不会发送请求，也不会消耗请求额度。缓存的键为请求地址加上除 `t`、`sign` 以外的查询参数，
+//! 只缓存成功的响应。
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use qweather_sdk::{cache::ResponseCache, client::ClientConfig};
+//!
+//! let cache = ResponseCache::memory(1000).with_ttl("/v7/weather/now", Duration::from_secs(300));
+//! let mut config = ClientConfig::new("id", "key");
+//! config.cache = Some(cache.clone());
+//!
+//! assert_eq!(cache.stats().hits, 0);
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::transport::url_path;
+
+/// 缓存存储
+pub trait CacheStore: Send + Sync {
+    /// 读取未过期的缓存
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+    /// 写入缓存，`ttl` 后过期
+    fn put(&self, key: String, value: Vec<u8>, ttl: Duration);
+}
+
+#[derive(Debug)]
+struct MemoryEntry {
+    value: Vec<u8>,
+    expires: Instant,
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    entries: HashMap<String, MemoryEntry>,
+    clock: u64,
+}
+
+/// 内存LRU缓存，超过容量时淘汰最久未使用的条目
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    state: Mutex<MemoryState>,
+}
+
+impl MemoryCache {
+    /// 最多缓存 `capacity` 个响应
+    pub fn new(capacity: usize) -> Self {
+        MemoryCache {
+            capacity,
+            state: Default::default(),
+        }
+    }
+
+    /// 当前缓存的条目数，包括已过期但尚未淘汰的条目
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut state = self.lock();
+        state.clock += 1;
+        let clock = state.clock;
+        match state.entries.get_mut(key) {
+            Some(entry) if entry.expires > Instant::now() => {
+                entry.last_used = clock;
+                Some(entry.value.clone())
+            }
+            Some(_) => {
+                state.entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn put(&self, key: String, value: Vec<u8>, ttl: Duration) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut state = self.lock();
+        state.clock += 1;
+        let now = Instant::now();
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.capacity {
+            state.entries.retain(|_, entry| entry.expires > now);
+        }
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.capacity {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+        let entry = MemoryEntry {
+            value,
+            expires: now + ttl,
+            last_used: state.clock,
+        };
+        state.entries.insert(key, entry);
+    }
+}
+
+/// 缓存命中统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// 命中次数
+    pub hits: u64,
+    /// 未命中次数
+    pub misses: u64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// 响应缓存
+///
+/// 有效期按请求路径前缀匹配，最长的前缀优先，没有匹配的接口不缓存。克隆后共享同一份存储和统计。
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttls: Arc<Vec<(String, Duration)>>,
+    counters: Arc<Counters>,
+}
+
+impl ResponseCache {
+    /// 使用自定义存储和默认有效期创建缓存
+    ///
+    /// | 接口 | 有效期 |
+    /// | --- | --- |
+    /// | 实时天气、格点实时天气 `/v7/weather/now` `/v7/grid-weather/now` | 10分钟 |
+    /// | 天气预报、格点天气预报 `/v7/weather/` `/v7/grid-weather/` | 1小时 |
+    /// | 分钟级降水 `/v7/minutely/` | 5分钟 |
+    /// | 天气灾害预警 `/v7/warning/` | 5分钟 |
+    /// | 天气指数 `/v7/indices/` | 1小时 |
+    /// | 台风 `/v7/tropical/` | 10分钟 |
+    /// | 空气质量 `/airquality/` | 10分钟 |
+    /// | 城市搜索、POI `/v2/` | 30天 |
+    pub fn new(store: impl CacheStore + 'static) -> Self {
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+        let ttls = [
+            ("/v7/weather/now", minutes(10)),
+            ("/v7/weather/", minutes(60)),
+            ("/v7/grid-weather/now", minutes(10)),
+            ("/v7/grid-weather/", minutes(60)),
+            ("/v7/minutely/", minutes(5)),
+            ("/v7/warning/", minutes(5)),
+            ("/v7/indices/", minutes(60)),
+            ("/v7/tropical/", minutes(10)),
+            ("/airquality/", minutes(10)),
+            ("/v2/", minutes(30 * 24 * 60)),
+        ];
+
+        ResponseCache {
+            store: Arc::new(store),
+            ttls: Arc::new(
+                ttls.into_iter()
+                    .map(|(prefix, ttl)| (prefix.to_string(), ttl))
+                    .collect(),
+            ),
+            counters: Default::default(),
+        }
+    }
+
+    /// 使用最多缓存 `capacity` 个响应的 [`MemoryCache`]
+    pub fn memory(capacity: usize) -> Self {
+        Self::new(MemoryCache::new(capacity))
+    }
+
+    /// 设置路径前缀的有效期，为零时不缓存
+    pub fn with_ttl(mut self, path_prefix: impl ToString, ttl: Duration) -> Self {
+        let path_prefix = path_prefix.to_string();
+        let ttls = Arc::make_mut(&mut self.ttls);
+        ttls.retain(|(prefix, _)| *prefix != path_prefix);
+        ttls.push((path_prefix, ttl));
+        self
+    }
+
+    /// 请求地址对应的有效期，不缓存时返回 `None`
+    pub fn ttl_for(&self, url: &str) -> Option<Duration> {
+        let path = url_path(url);
+        self.ttls
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, ttl)| *ttl)
+            .filter(|ttl| !ttl.is_zero())
+    }
+
+    /// 命中统计
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// 缓存的键，不含时间戳和签名
+    pub(crate) fn key(url: &str, params: &BTreeMap<String, String>) -> String {
+        let query = params
+            .iter()
+            .filter(|(key, _)| key.as_str() != "t" && key.as_str() != "sign")
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        format!("{}?{}", url, query)
+    }
+
+    /// 读取缓存并记录命中统计
+    pub(crate) fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let value = self.store.get(key);
+        let counter = match value {
+            Some(_) => &self.counters.hits,
+            None => &self.counters.misses,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        value
+    }
+
+    /// 写入缓存
+    pub(crate) fn put(&self, key: String, value: Vec<u8>, ttl: Duration) {
+        self.store.put(key, value, ttl);
+    }
+}
+
+impl fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("ttls", &self.ttls)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+#[test]
+fn test_memory_cache() {
+    let cache = MemoryCache::new(2);
+    let ttl = Duration::from_secs(60);
+    cache.put("a".to_string(), b"1".to_vec(), ttl);
+    cache.put("b".to_string(), b"2".to_vec(), ttl);
+    assert_eq!(cache.get("a"), Some(b"1".to_vec()));
+    // b 最久未使用，被淘汰
+    cache.put("c".to_string(), b"3".to_vec(), ttl);
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.get("a"), Some(b"1".to_vec()));
+    assert_eq!(cache.get("c"), Some(b"3".to_vec()));
+    assert_eq!(cache.len(), 2);
+
+    cache.put("a".to_string(), b"4".to_vec(), Duration::ZERO);
+    assert_eq!(cache.get("a"), None);
+    assert_eq!(cache.len(), 1);
+
+    // 持有锁的线程 panic 后缓存仍然可用
+    std::thread::scope(|scope| {
+        let poison = scope.spawn(|| {
+            let _state = cache.lock();
+            panic!("poison the lock");
+        });
+        assert!(poison.join().is_err());
+    });
+    assert!(cache.state.is_poisoned());
+    assert_eq!(cache.get("c"), Some(b"3".to_vec()));
+
+    let cache = ResponseCache::memory(10).with_ttl("/v7/weather/3d", Duration::from_secs(1));
+    let ttl = |url| cache.ttl_for(url).map(|ttl| ttl.as_secs());
+    assert_eq!(ttl("https://devapi.qweather.com/v7/weather/now"), Some(600));
+    assert_eq!(ttl("https://devapi.qweather.com/v7/weather/7d"), Some(3600));
+    assert_eq!(ttl("https://devapi.qweather.com/v7/weather/3d"), Some(1));
+    assert_eq!(ttl("https://devapi.qweather.com/v7/warning/now"), Some(300));
+    assert_eq!(
+        ttl("https://geoapi.qweather.com/v2/city/lookup"),
+        Some(2592000)
+    );
+    assert_eq!(ttl("https://devapi.qweather.com/v7/unknown"), None);
+    let cache = cache.with_ttl("/v7/warning/", Duration::ZERO);
+    assert_eq!(
+        cache.ttl_for("https://devapi.qweather.com/v7/warning/now"),
+        None
+    );
+
+    let mut params = BTreeMap::new();
+    params.insert("location".to_string(), "101010100".to_string());
+    params.insert("t".to_string(), "1".to_string());
+    params.insert("sign".to_string(), "abc".to_string());
+    params.insert("lang".to_string(), "zh".to_string());
+    assert_eq!(
+        ResponseCache::key("https://devapi.qweather.com/v7/weather/now", &params),
+        "https://devapi.qweather.com/v7/weather/now?lang=zh&location=101010100"
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_client_cache() {
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        transport::MockTransport,
+    };
+
+    let transport = MockTransport::new();
+    transport.push_json(serde_json::json!({ "code": "401" }));
+    transport.push_json(serde_json::json!({ "code": "200", "value": 1 }));
+    transport.push_json(serde_json::json!({ "code": "200", "value": 2 }));
+    let cache = ResponseCache::memory(10);
+    let mut config = ClientConfig::new("id", "key");
+    config.cache = Some(cache.clone());
+    let client = QWeatherClient::with_transport(config, transport.clone());
+
+    let request = |location: &str| {
+        let mut params = BTreeMap::new();
+        params.insert("location".to_string(), location.to_string());
+        client.request_api::<serde_json::Value>(
+            "https://devapi.qweather.com/v7/weather/now".to_string(),
+            params,
+        )
+    };
+    let value = |resp: APIResponse<serde_json::Value>| match resp {
+        APIResponse::Success(value) => value["value"].clone(),
+        APIResponse::Error(code) => serde_json::Value::String(code),
+        APIResponse::ParseError { error, .. } => serde_json::Value::String(error),
+    };
+
+    // 错误响应不缓存
+    assert_eq!(value(request("101010100").await.unwrap()), "401");
+    assert_eq!(value(request("101010100").await.unwrap()), 1);
+    assert_eq!(value(request("101010100").await.unwrap()), 1);
+    assert_eq!(value(request("101020100").await.unwrap()), 2);
+    assert_eq!(transport.requests().len(), 3);
+    assert_eq!(cache.stats(), CacheStats { hits: 1, misses: 3 });
+}
@@ -0,0 +1,456 @@
+//! 录制和回放
+//!
+//! [`CassetteTransport`] 在录制模式下通过真实的传输发送请求，并把请求和响应保存到录制文件中；
+//! 回放模式下从录制文件中查找相同的请求返回响应，不访问网络，适合在CI中重复运行。
+//!
+//! 请求按接口路径和未签名的参数匹配：录制时去掉每次都会变化的时间戳 `t` 和签名 `sign`，
+//! `publicid` 替换为 `REDACTED`，接口地址只保存路径部分，回放时可以使用任意的密钥和API Host。
+//! 回放时找不到匹配的请求会返回 [`Error::Cassette`]，列出与最接近的录制请求之间的差异，
+//! 客户端的方法返回包含该错误信息的 [`APIResponse::Error`](crate::api::APIResponse::Error)。
+//!
+//! ```no_run
+//! use qweather_sdk::{
+//!     cassette::CassetteTransport,
+//!     client::{ClientConfig, QWeatherClient},
+//! };
+//!
+//! # fn run() -> Result<(), qweather_sdk::Error> {
+//! // 录制文件不存在时录制，存在时回放
+//! let transport = CassetteTransport::auto("tests/cassettes/weather_now.json")?;
+//! let client = QWeatherClient::with_transport(ClientConfig::new("id", "key"), transport);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    transport::{url_path, BoxFuture, Request, ReqwestTransport, Response, Transport},
+    Error,
+};
+
+/// 录制时替换 `publicid` 的值
+pub const REDACTED: &str = "REDACTED";
+
+/// 录制的请求
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// 接口路径，例如 `/v7/weather/now`
+    pub endpoint: String,
+    /// 未签名的查询参数
+    pub params: BTreeMap<String, String>,
+}
+
+impl RecordedRequest {
+    /// 去掉请求中的时间戳和签名，隐藏公钥
+    pub fn from_request(request: &Request) -> Self {
+        let params = request
+            .params
+            .iter()
+            .filter(|(key, _)| !matches!(key.as_str(), "t" | "sign"))
+            .map(|(key, value)| {
+                let value = match key.as_str() {
+                    "publicid" | "key" => REDACTED.to_string(),
+                    _ => value.clone(),
+                };
+                (key.clone(), value)
+            })
+            .collect();
+
+        RecordedRequest {
+            endpoint: url_path(&request.url).to_string(),
+            params,
+        }
+    }
+
+    /// 与另一个请求的差异，每行一项，相同时为空
+    fn diff(&self, actual: &RecordedRequest) -> Vec<String> {
+        let mut diff = vec![];
+        if self.endpoint != actual.endpoint {
+            diff.push(format!(
+                "  endpoint: recorded {:?}, actual {:?}",
+                self.endpoint, actual.endpoint
+            ));
+        }
+        let keys: BTreeSet<_> = self.params.keys().chain(actual.params.keys()).collect();
+        for key in keys {
+            match (self.params.get(key), actual.params.get(key)) {
+                (Some(recorded), Some(value)) if recorded != value => diff.push(format!(
+                    "  {}: recorded {:?}, actual {:?}",
+                    key, recorded, value
+                )),
+                (Some(recorded), None) => {
+                    diff.push(format!("- {}: {:?} (only recorded)", key, recorded))
+                }
+                (None, Some(value)) => diff.push(format!("+ {}: {:?} (only actual)", key, value)),
+                _ => {}
+            }
+        }
+        diff
+    }
+}
+
+/// 录制的响应体
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordedBody {
+    /// JSON响应体
+    Json(Value),
+    /// 非JSON的响应体
+    Text(String),
+}
+
+/// 录制的响应
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// HTTP状态码
+    pub status: u16,
+    /// 响应头，名称均为小写
+    pub headers: BTreeMap<String, String>,
+    /// 响应体
+    pub body: RecordedBody,
+}
+
+impl From<&Response> for RecordedResponse {
+    fn from(response: &Response) -> Self {
+        let body = match serde_json::from_slice(&response.body) {
+            Ok(json) => RecordedBody::Json(json),
+            Err(_) => RecordedBody::Text(String::from_utf8_lossy(&response.body).into_owned()),
+        };
+
+        RecordedResponse {
+            status: response.status,
+            headers: response.headers.clone(),
+            body,
+        }
+    }
+}
+
+impl From<&RecordedResponse> for Response {
+    fn from(recorded: &RecordedResponse) -> Self {
+        let body = match &recorded.body {
+            RecordedBody::Json(json) => json.to_string().into_bytes(),
+            RecordedBody::Text(text) => text.clone().into_bytes(),
+        };
+
+        Response {
+            status: recorded.status,
+            headers: recorded.headers.clone(),
+            body,
+        }
+    }
+}
+
+/// 一次请求和响应
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// 请求
+    pub request: RecordedRequest,
+    /// 响应
+    pub response: RecordedResponse,
+}
+
+/// 录制文件的内容
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// 按请求顺序保存的请求和响应
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// 读取录制文件
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| Error::Cassette(format!("failed to read {}: {}", path.display(), e)))?;
+        serde_json::from_str(&content)
+            .map_err(|e| Error::Cassette(format!("failed to parse {}: {}", path.display(), e)))
+    }
+
+    /// 保存录制文件，自动创建上级目录
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let mut content = serde_json::to_string_pretty(self)?;
+            content.push('\n');
+            fs::write(path, content)
+        };
+        write().map_err(|e| Error::Cassette(format!("failed to write {}: {}", path.display(), e)))
+    }
+}
+
+enum Mode {
+    Record(Arc<dyn Transport>),
+    Replay,
+}
+
+struct CassetteState {
+    cassette: Cassette,
+    /// 回放时已使用过的录制请求
+    used: Vec<bool>,
+}
+
+/// 录制或回放请求的传输
+#[derive(Clone)]
+pub struct CassetteTransport {
+    path: PathBuf,
+    mode: Arc<Mode>,
+    state: Arc<Mutex<CassetteState>>,
+}
+
+impl fmt::Debug for CassetteTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CassetteTransport")
+            .field("path", &self.path)
+            .field("recording", &self.is_recording())
+            .field("interactions", &self.lock().cassette.interactions.len())
+            .finish()
+    }
+}
+
+impl CassetteTransport {
+    /// 使用 [`ReqwestTransport`] 录制，覆盖已有的录制文件，无法创建 reqwest 客户端时返回错误
+    pub fn record(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let client = reqwest::ClientBuilder::new().gzip(true).build()?;
+        Ok(Self::record_with(path, ReqwestTransport::new(client)))
+    }
+
+    /// 通过 `inner` 发送请求并录制，覆盖已有的录制文件
+    pub fn record_with(path: impl Into<PathBuf>, inner: impl Transport + 'static) -> Self {
+        Self::new(
+            path.into(),
+            Mode::Record(Arc::new(inner)),
+            Cassette::default(),
+        )
+    }
+
+    /// 回放录制文件
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        Ok(Self::new(path, Mode::Replay, cassette))
+    }
+
+    /// 录制文件存在时回放，否则使用 [`ReqwestTransport`] 录制
+    pub fn auto(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+        if path.exists() {
+            Self::replay(path)
+        } else {
+            Self::record(path)
+        }
+    }
+
+    fn new(path: PathBuf, mode: Mode, cassette: Cassette) -> Self {
+        let used = vec![false; cassette.interactions.len()];
+        CassetteTransport {
+            path,
+            mode: Arc::new(mode),
+            state: Arc::new(Mutex::new(CassetteState { cassette, used })),
+        }
+    }
+
+    /// 录制文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 是否为录制模式
+    pub fn is_recording(&self) -> bool {
+        matches!(*self.mode, Mode::Record(_))
+    }
+
+    /// 已录制或读取的请求和响应
+    pub fn cassette(&self) -> Cassette {
+        self.lock().cassette.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CassetteState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn replay_request(&self, request: &Request) -> Result<Response, Error> {
+        let actual = RecordedRequest::from_request(request);
+        let mut state = self.lock();
+        let CassetteState { cassette, used } = &mut *state;
+
+        // 优先使用未使用过的录制请求，重复的请求返回最后一次录制的响应
+        let matching: Vec<_> = cassette
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| interaction.request == actual)
+            .map(|(i, _)| i)
+            .collect();
+        if let Some(&i) = matching.iter().find(|&&i| !used[i]).or(matching.last()) {
+            used[i] = true;
+            return Ok(Response::from(&cassette.interactions[i].response));
+        }
+
+        Err(Error::Cassette(self.mismatch(cassette, &actual)))
+    }
+
+    /// 找不到匹配请求时的错误信息，列出与最接近的录制请求之间的差异
+    fn mismatch(&self, cassette: &Cassette, actual: &RecordedRequest) -> String {
+        let mut message = format!(
+            "no recorded response in {} for {} {:?}",
+            self.path.display(),
+            actual.endpoint,
+            actual.params
+        );
+        let closest = cassette
+            .interactions
+            .iter()
+            .map(|interaction| interaction.request.diff(actual))
+            .enumerate()
+            .min_by_key(|(i, diff)| {
+                // 优先比较同一接口的请求
+                let other_endpoint = cassette.interactions[*i].request.endpoint != actual.endpoint;
+                (other_endpoint, diff.len())
+            });
+        match closest {
+            Some((i, diff)) => {
+                message.push_str(&format!(
+                    "\nclosest recorded request #{} {}:\n{}",
+                    i,
+                    cassette.interactions[i].request.endpoint,
+                    diff.join("\n")
+                ));
+            }
+            None => message.push_str("\ncassette is empty"),
+        }
+        message
+    }
+}
+
+impl Transport for CassetteTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async move {
+            let Mode::Record(inner) = &*self.mode else {
+                return self.replay_request(&request);
+            };
+
+            let recorded = RecordedRequest::from_request(&request);
+            let response = inner.send(request).await?;
+            let cassette = {
+                let mut state = self.lock();
+                state.cassette.interactions.push(Interaction {
+                    request: recorded,
+                    response: RecordedResponse::from(&response),
+                });
+                state.cassette.clone()
+            };
+            cassette.save(&self.path)?;
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_cassette_record_and_replay() {
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        transport::MockTransport,
+    };
+
+    let path = std::env::temp_dir().join(format!(
+        "qweather-cassette-{}-{:?}.json",
+        std::process::id(),
+        std::thread::current().id()
+    ));
+
+    let mock = MockTransport::new();
+    mock.push_json(serde_json::json!({
+        "code": "200",
+        "updateTime": "2020-06-30T22:00+08:00",
+        "fxLink": "http://hfx.link/2ax1",
+        "now": {
+          "obsTime": "2020-06-30T21:40+08:00",
+          "temp": "24",
+          "feelsLike": "26",
+          "icon": "101",
+          "text": "多云",
+          "wind360": "123",
+          "windDir": "东南风",
+          "windScale": "1",
+          "windSpeed": "3",
+          "humidity": "72",
+          "precip": "0.0",
+          "pressure": "1003",
+          "vis": "16",
+          "cloud": "10",
+          "dew": "21"
+        },
+        "refer": { "sources": [], "license": [] }
+    }));
+    mock.push_response(Response {
+        status: 502,
+        headers: BTreeMap::new(),
+        body: b"Bad Gateway".to_vec(),
+    });
+    let transport = CassetteTransport::record_with(&path, mock);
+    assert!(transport.is_recording());
+    let client = QWeatherClient::with_transport(
+        ClientConfig::new("secret-id", "secret-key"),
+        transport.clone(),
+    );
+    let recorded = client.weather_now("101010100").await.unwrap();
+    assert!(matches!(
+        client.weather_daily_forecast("101010100", 3).await.unwrap(),
+        APIResponse::Error(code) if code == "502"
+    ));
+
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("secret-id"));
+    assert!(!content.contains("\"sign\""));
+    assert!(!content.contains("\"t\""));
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette, transport.cassette());
+    assert_eq!(cassette.interactions.len(), 2);
+    assert_eq!(cassette.interactions[0].request.endpoint, "/v7/weather/now");
+    assert_eq!(
+        cassette.interactions[0].request.params["publicid"],
+        REDACTED
+    );
+    assert_eq!(
+        cassette.interactions[1].response.body,
+        RecordedBody::Text("Bad Gateway".to_string())
+    );
+
+    // 使用其他密钥和API Host回放
+    let mut config = ClientConfig::new("other-id", "other-key");
+    config.api_host = Some("https://api.qweather.com".to_string());
+    let client = QWeatherClient::with_transport(config, CassetteTransport::replay(&path).unwrap());
+    let replayed = client.weather_now("101010100").await.unwrap();
+    assert_eq!(format!("{:?}", replayed), format!("{:?}", recorded));
+    // 重复的请求返回同样的响应
+    assert!(matches!(
+        client.weather_now("101010100").await.unwrap(),
+        APIResponse::Success(_)
+    ));
+
+    let message = match client.weather_now("101020100").await.unwrap() {
+        APIResponse::Error(message) => message,
+        other => panic!("unexpected {:?}", other),
+    };
+    assert!(message.starts_with("cassette error: no recorded response"));
+    assert!(message.contains("closest recorded request #0 /v7/weather/now"));
+    assert!(message.contains(r#"location: recorded "101010100", actual "101020100""#));
+
+    let _ = fs::remove_file(&path);
+    assert!(CassetteTransport::replay(&path).is_err());
+}
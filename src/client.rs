@@ -1,19 +1,50 @@
-use crate::{api::APIResponse, WEATHER_API_URL, WEATHER_DEV_API_URL};
-use log::trace;
-use md5::{Digest, Md5};
+use crate::{
+    api::{APIResponse, RawResponse, SUBSCRIPTION_ONLY_PATHS},
+    cache::ResponseCache,
+    clock::{self, Clock, SystemClock},
+    coalesce::SingleFlight,
+    instrument::{self, RequestSpan},
+    key_pool::{KeyPool, SelectedKey},
+    middleware::{Context, Middleware},
+    rate_limit::{EndpointFamily, RateLimiter},
+    retry::{retry_after, RetryPolicy},
+    signer::Signer,
+    transport::{url_path, Request, ReqwestTransport, Response, Transport},
+    Error, GEO_API_URL, WEATHER_API_URL, WEATHER_DEV_API_URL,
+};
+use chrono::{DateTime, Utc};
+#[cfg(not(feature = "tracing"))]
+use log::{trace, warn};
 use reqwest::{Client, ClientBuilder};
 use serde_json::Value;
-use std::collections::BTreeMap;
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::time::sleep;
+#[cfg(feature = "tracing")]
+use tracing::{trace, warn};
 
 /// 天气API客户端
 pub struct QWeatherClient {
     api_host: String,
-    /// 客户端
-    client: Client,
+    geo_api_host: String,
+    /// HTTP传输
+    transport: Arc<dyn Transport>,
     /// 基础查询参数
     base_params: BTreeMap<String, String>,
     /// 客户端配置
     client_config: ClientConfig,
+    /// 正在执行的请求
+    inflight: SingleFlight,
+    /// 请求签名器
+    signer: Signer,
+    /// 服务器时间与本机时间的差，单位秒
+    skew: AtomicI64,
 }
 
 /// api 客户端配置
@@ -28,6 +59,27 @@ pub struct ClientConfig {
     pub lang: Option<String>,
     /// 数据单位设置，可选值包括unit=m（公制单位，默认）和unit=i（英制单位）。更多选项和说明参考度[量衡单位](https://dev.qweather.com/docs/resource/unit)。
     pub unit: Option<String>,
+    /// 自定义API Host，例如代理或测试服务器地址，为空时根据是否订阅选择
+    pub api_host: Option<String>,
+    /// 自定义GEO API Host，为空时使用 [`GEO_API_URL`]
+    pub geo_api_host: Option<String>,
+    /// 重试策略，默认不重试
+    pub retry: RetryPolicy,
+    /// 客户端限流和每日请求额度，默认不限制
+    pub rate_limiter: Option<RateLimiter>,
+    /// 响应缓存，默认不缓存
+    pub cache: Option<ResponseCache>,
+    /// 是否合并并发的相同请求，默认开启
+    pub coalesce: bool,
+    /// 请求中间件，按顺序调用
+    pub middleware: Vec<Arc<dyn Middleware>>,
+    /// 签名用的时钟，默认使用系统时间
+    pub clock: Arc<dyn Clock>,
+    /// 是否根据响应头 `Date` 修正本机与服务器的时间差，默认关闭
+    pub skew_correction: bool,
+    /// 多密钥池，设置后按密钥池轮询签名，`public_id` 和 `private_key` 不再用于签名；
+    /// 密钥池中没有可用于该接口的密钥时返回 [`Error::NoEligibleKey`]
+    pub key_pool: Option<KeyPool>,
 }
 
 impl ClientConfig {
@@ -39,35 +91,83 @@ impl ClientConfig {
             subscription: false,
             lang: Some("zh".to_string()),
             unit: None,
+            api_host: None,
+            geo_api_host: None,
+            retry: RetryPolicy::default(),
+            rate_limiter: None,
+            cache: None,
+            coalesce: true,
+            middleware: Vec::new(),
+            clock: Arc::new(SystemClock),
+            skew_correction: false,
+            key_pool: None,
         }
     }
+
+    /// 添加请求中间件
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
 }
 
 impl QWeatherClient {
     /// 使用配置创建新的客户端
+    ///
+    /// # Panics
+    ///
+    /// 无法创建 reqwest 客户端时 panic，需要处理该错误时请使用 [`QWeatherClient::try_with_config`]
     pub fn with_config(client_config: ClientConfig) -> Self {
-        let api_host = if client_config.subscription {
-            WEATHER_API_URL.to_string()
-        } else {
-            WEATHER_DEV_API_URL.to_string()
-        };
+        Self::try_with_config(client_config).expect("Failed to create reqwest client")
+    }
+
+    /// 使用配置创建新的客户端，无法创建 reqwest 客户端时返回错误
+    pub fn try_with_config(client_config: ClientConfig) -> Result<Self, Error> {
+        let client = ClientBuilder::new().gzip(true).build()?;
+
+        Ok(Self::with_http_client(client_config, client))
+    }
 
-        let client = ClientBuilder::new()
-            .gzip(true)
-            .build()
-            .expect("Failed to create reqwest client");
+    /// 使用配置和已有的 reqwest 客户端创建新的客户端，例如设置了代理、根证书或超时的客户端
+    pub fn with_http_client(client_config: ClientConfig, client: Client) -> Self {
+        Self::with_transport(client_config, ReqwestTransport::new(client))
+    }
 
+    /// 使用配置和自定义传输创建新的客户端
+    pub fn with_transport(
+        client_config: ClientConfig,
+        transport: impl Transport + 'static,
+    ) -> Self {
+        let api_host = match &client_config.api_host {
+            Some(api_host) => api_host.to_string(),
+            None if client_config.subscription => WEATHER_API_URL.to_string(),
+            None => WEATHER_DEV_API_URL.to_string(),
+        };
+        let geo_api_host = client_config
+            .geo_api_host
+            .as_deref()
+            .unwrap_or(GEO_API_URL)
+            .to_string();
+
+        let signer = Signer::new(&client_config.public_id, &client_config.private_key);
         let mut base_params = BTreeMap::new();
         base_params.insert("publicid".to_string(), client_config.public_id.to_string());
         if let Some(lang) = &client_config.lang {
             base_params.insert("lang".to_string(), lang.to_string());
         }
+        if let Some(unit) = &client_config.unit {
+            base_params.insert("unit".to_string(), unit.to_string());
+        }
 
         QWeatherClient {
             api_host,
-            client,
+            geo_api_host,
+            transport: Arc::new(transport),
             base_params,
             client_config,
+            inflight: SingleFlight::default(),
+            signer,
+            skew: AtomicI64::new(0),
         }
     }
 
@@ -79,95 +179,535 @@ impl QWeatherClient {
         lang: impl ToString,
         unit: impl ToString,
     ) -> Self {
-        let api_host = if subscription {
-            WEATHER_API_URL.to_string()
-        } else {
-            WEATHER_DEV_API_URL.to_string()
+        Self::with_config(ClientConfig {
+            public_id: public_id.to_string(),
+            private_key: private_key.to_string(),
+            subscription,
+            lang: Some(lang.to_string()),
+            unit: Some(unit.to_string()),
+            api_host: None,
+            geo_api_host: None,
+            retry: RetryPolicy::default(),
+            rate_limiter: None,
+            cache: None,
+            coalesce: true,
+            middleware: Vec::new(),
+            clock: Arc::new(SystemClock),
+            skew_correction: false,
+            key_pool: None,
+        })
+    }
+
+    /// 获取API Host
+    pub fn get_api_host(&self) -> &str {
+        &self.api_host
+    }
+
+    /// 获取GEO API Host
+    pub fn get_geo_api_host(&self) -> &str {
+        &self.geo_api_host
+    }
+
+    /// 获取限流器，可用于查询剩余的每日请求额度
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.client_config.rate_limiter.as_ref()
+    }
+
+    /// 获取请求签名器，可用于生成签名URL
+    pub fn signer(&self) -> &Signer {
+        &self.signer
+    }
+
+    /// 签名用的当前时间，开启 [`ClientConfig::skew_correction`] 时已修正与服务器的时间差
+    pub fn now(&self) -> DateTime<Utc> {
+        self.client_config.clock.now()
+            + chrono::Duration::seconds(self.skew.load(Ordering::Relaxed))
+    }
+
+    /// 从密钥池中为请求选择密钥，未设置密钥池时返回 `None`
+    fn select_key(&self, url: &str, tried: &[usize]) -> Option<SelectedKey> {
+        let pool = self.client_config.key_pool.as_ref()?;
+        // 未设置自定义API Host时按密钥选择Host
+        let rewrite_host = self.client_config.api_host.is_none()
+            && (url.starts_with(WEATHER_API_URL) || url.starts_with(WEATHER_DEV_API_URL));
+
+        pool.select(url, subscription_only(url), rewrite_host, tried)
+    }
+
+    /// 密钥池中可用于该地址的密钥数量，未设置密钥池时为 `None`
+    fn eligible_keys(&self, url: &str) -> Option<usize> {
+        let pool = self.client_config.key_pool.as_ref()?;
+
+        Some(pool.eligible(subscription_only(url)))
+    }
+
+    /// 根据响应头 `Date` 更新时间差，返回签名时间戳是否与服务器时间相差超过1分钟
+    fn correct_skew(&self, response: &Response, timestamp: i64) -> bool {
+        let Some(server_now) = clock::server_date(response) else {
+            return false;
         };
+        let skew = (server_now - self.client_config.clock.now()).num_seconds();
+        self.skew.store(skew, Ordering::Relaxed);
 
-        let client = ClientBuilder::new()
-            .gzip(true)
-            .build()
-            .expect("Failed to create reqwest client");
+        (server_now.timestamp() - timestamp).abs() > 60
+    }
 
-        let mut base_params = BTreeMap::new();
-        base_params.insert("publicid".to_string(), public_id.to_string());
+    /// 获取多密钥池，可用于查询各密钥的统计
+    pub fn key_pool(&self) -> Option<&KeyPool> {
+        self.client_config.key_pool.as_ref()
+    }
 
-        QWeatherClient {
-            api_host,
-            client,
-            base_params,
-            client_config: ClientConfig {
-                public_id: public_id.to_string(),
-                private_key: private_key.to_string(),
-                subscription,
-                lang: Some(lang.to_string()),
-                unit: Some(unit.to_string()),
-            },
-        }
+    /// 获取响应缓存，可用于查询命中统计
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.client_config.cache.as_ref()
     }
 
-    /// 获取API Host
-    pub fn get_api_host(&self) -> &str {
-        &self.api_host
+    /// 仅限订阅用户的API Host，设置了自定义API Host时使用自定义值
+    pub(crate) fn get_subscription_api_host(&self) -> &str {
+        self.client_config
+            .api_host
+            .as_deref()
+            .unwrap_or(WEATHER_API_URL)
     }
 
-    /// 请求API
+    /// 请求API并按状态码解析响应
+    ///
+    /// 开启 [`ClientConfig::coalesce`] 时，并发的相同请求只发送和解析一次，解析结果或错误克隆给所有调用方。
     pub async fn request_api<T>(
+        &self,
+        url: String,
+        params: BTreeMap<String, String>,
+    ) -> Result<APIResponse<T>, Error>
+    where
+        T: serde::de::DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        self.request(url, params, |raw| raw.and_then(RawResponse::decode))
+            .await
+    }
+
+    /// 请求API，返回未解析的原始响应
+    ///
+    /// 收到JSON响应时返回 [`APIResponse::Success`]，包括状态码不为200的响应；传输失败或非JSON的错误响应返回
+    /// [`APIResponse::Error`]。
+    pub async fn request_raw(
+        &self,
+        url: String,
+        params: BTreeMap<String, String>,
+    ) -> Result<APIResponse<RawResponse>, Error> {
+        self.request(url, params, |raw| raw).await
+    }
+
+    /// 合并参数，读取缓存或发送请求，并用 `decode` 转换响应
+    ///
+    /// 合并并发请求时以请求地址、参数和结果类型为键，`decode` 只由第一个调用方执行。
+    async fn request<T>(
         &self,
         url: String,
         mut params: BTreeMap<String, String>,
-    ) -> Result<APIResponse<T>, reqwest::Error>
+        decode: impl FnOnce(APIResponse<RawResponse>) -> APIResponse<T>,
+    ) -> Result<APIResponse<T>, Error>
     where
-        T: serde::de::DeserializeOwned,
+        T: Clone + Send + Sync + 'static,
     {
         // 合并参数
         params.extend(self.base_params.clone());
-        params.insert(
-            "t".to_string(),
-            chrono::Local::now().timestamp().to_string(),
-        );
-        let sign = self.sign_params(&params);
-        params.insert("sign".to_string(), sign);
-        match self.client.get(&url).query(&params).send().await {
-            Ok(response) => {
-                let body: Value = response.json().await?;
-                trace!("Response: {:?}", body);
-                match body["code"].as_str() {
-                    Some("200") | None => match serde_json::from_value::<T>(body) {
-                        Ok(response) => Ok(APIResponse::Success(response)),
-                        Err(e) => {
-                            log::error!("Failed to parse response: {}", e);
-                            Ok(APIResponse::Error("Failed to parse response".to_string()))
+        let key = ResponseCache::key(&url, &params);
+        let request = async {
+            let cache = self
+                .client_config
+                .cache
+                .as_ref()
+                .and_then(|cache| Some((cache, cache.ttl_for(&url)?, key.as_str())));
+            if let Some((cache, _, key)) = cache {
+                let body = cache.get(key);
+                instrument::record_cache(&url, body.is_some());
+                if let Some(body) = body {
+                    trace!("Cache hit: {}", url);
+                    return Ok(decode(APIResponse::Success(RawResponse {
+                        status: 200,
+                        headers: BTreeMap::new(),
+                        body: serde_json::from_slice(&body)?,
+                    })));
+                }
+            }
+
+            Ok(decode(self.fetch(&url, &params, cache).await?))
+        };
+        if self.client_config.coalesce {
+            self.inflight.run(key.clone(), request).await
+        } else {
+            request.await
+        }
+    }
+
+    /// 发送请求，按重试策略重试
+    async fn fetch(
+        &self,
+        url: &str,
+        params: &BTreeMap<String, String>,
+        cache: Option<(&ResponseCache, Duration, &str)>,
+    ) -> Result<APIResponse<RawResponse>, Error> {
+        let retry = &self.client_config.retry;
+        if self.eligible_keys(url) == Some(0) {
+            return Err(Error::NoEligibleKey(url_path(url).to_string()));
+        }
+        let mut attempt = 1;
+        loop {
+            let span = RequestSpan::new(url, params, attempt);
+            match span
+                .instrument(self.send_signed(url, params, attempt))
+                .await
+            {
+                Ok(response) => {
+                    let body = serde_json::from_slice::<Value>(&response.body);
+                    let code = body
+                        .as_ref()
+                        .ok()
+                        .and_then(|body| body["code"].as_str())
+                        .map(str::to_string);
+                    span.record_response(response.status, code.as_deref());
+                    if code.as_deref() == Some("402") {
+                        if let Some(limiter) = &self.client_config.rate_limiter {
+                            limiter.exhaust();
+                        }
+                    }
+                    if attempt < retry.max_attempts
+                        && retry.should_retry_response(response.status, code.as_deref())
+                    {
+                        match retry.delay(attempt, retry_after(&response)) {
+                            Some(delay) => {
+                                warn!(
+                                    "Request failed with status {} code {:?}, retry in {:?}",
+                                    response.status, code, delay
+                                );
+                                sleep(delay).await;
+                                attempt += 1;
+                                continue;
+                            }
+                            None => warn!(
+                                "Request failed with status {} code {:?}, Retry-After exceeds max delay",
+                                response.status, code
+                            ),
+                        }
+                    }
+                    let body = match body {
+                        Ok(body) => body,
+                        // 非JSON的错误响应
+                        Err(_) if !(200..300).contains(&response.status) => {
+                            return Ok(APIResponse::Error(response.status.to_string()))
                         }
-                    },
-                    // v1 error
-                    Some(code) => Ok(APIResponse::Error(code.to_string())),
+                        Err(e) => return Err(e.into()),
+                    };
+                    trace!("Response from {}: {:?}", url, body);
+                    if matches!(code.as_deref(), Some("200") | None) {
+                        if let Some((cache, ttl, key)) = cache {
+                            cache.put(key.to_string(), response.body, ttl);
+                        }
+                    }
+                    return Ok(APIResponse::Success(RawResponse {
+                        status: response.status,
+                        headers: response.headers,
+                        body,
+                    }));
+                }
+                // 额度用完，请求未发送
+                Err(error @ Error::QuotaExhausted { .. }) => return Err(error),
+                Err(error) => {
+                    span.record_error(&error);
+                    if attempt < retry.max_attempts && retry.retry_transport_errors {
+                        // 没有 `Retry-After` 时总是有等待时间
+                        let delay = retry.delay(attempt, None).unwrap_or(retry.max_delay);
+                        warn!("Request failed: {}, retry in {:?}", error, delay);
+                        sleep(delay).await;
+                        attempt += 1;
+                        continue;
+                    }
+                    return Ok(APIResponse::Error(error.to_string()));
                 }
             }
-            Err(error) => Ok(APIResponse::Error(error.to_string())),
         }
     }
 
-    /// 签名参数
-    fn sign_params(&self, params: &BTreeMap<String, String>) -> String {
-        let mut sign = String::new();
+    /// 申请一次HTTP请求，超出速率时等待，每日额度用完时返回错误
+    async fn acquire(&self, family: EndpointFamily) -> Result<(), Error> {
+        if let Some(limiter) = &self.client_config.rate_limiter {
+            let wait = limiter.acquire(family)?;
+            if !wait.is_zero() {
+                trace!("Rate limited, wait {:?}", wait);
+                sleep(wait).await;
+            }
+        }
+        Ok(())
+    }
 
-        for (key, value) in params {
-            if key.to_lowercase() == "sign" || key.to_lowercase() == "key" {
-                continue;
+    /// 调用中间件，使用新的时间戳签名并发送请求
+    ///
+    /// 时间戳偏差重新签名和换用密钥重新发送的请求同样计入限流和每日额度，并且每次发送都单独调用中间件。
+    /// 第一次发送前额度用完时返回 [`Error::QuotaExhausted`]，重新发送前额度用完时返回上一次的响应。
+    /// 中间件直接返回响应时不签名、不发送，也不计入限流和额度。
+    async fn send_signed(
+        &self,
+        url: &str,
+        params: &BTreeMap<String, String>,
+        attempt: u32,
+    ) -> Result<Response, Error> {
+        let middleware = &self.client_config.middleware;
+        let mut resigned = false;
+        let mut tried = Vec::new();
+        let mut previous = None;
+        let mut send = 0;
+        loop {
+            send += 1;
+            let mut context = Context::new(url, attempt, send);
+            let mut request = Request::get(url, params.clone());
+            let mut layers = middleware.len();
+            let mut replaced = None;
+            for (index, layer) in middleware.iter().enumerate() {
+                if let Some(response) = layer.before_request(&mut request, &mut context) {
+                    layers = index;
+                    replaced = Some(response);
+                    break;
+                }
             }
-            if value.is_empty() {
-                continue;
+            let layers = &middleware[..layers];
+
+            let mut resend = false;
+            let result = 'send: {
+                if let Some(response) = replaced {
+                    break 'send Ok(response);
+                }
+                if let Err(error) = self.acquire(EndpointFamily::from_url(&request.url)).await {
+                    break 'send Err(error);
+                }
+                let url = request.url.clone();
+                let key = self.select_key(&url, &tried);
+                let signer = match &key {
+                    Some(key) => {
+                        request.url = key.url.clone();
+                        &key.signer
+                    }
+                    // 设置了密钥池时不使用配置中的密钥签名
+                    None if self.client_config.key_pool.is_some() => {
+                        break 'send Err(Error::NoEligibleKey(url_path(&url).to_string()));
+                    }
+                    None => &self.signer,
+                };
+                let timestamp = self.now().timestamp();
+                request.params = signer.signed_params(request.params.clone(), timestamp);
+                let result = self.transport.send(request.clone()).await;
+                match (&result, &key) {
+                    (Ok(response), key) => {
+                        let code = response_code(response);
+                        if self.client_config.skew_correction
+                            && self.correct_skew(response, timestamp)
+                            && !resigned
+                            && code.as_deref() == Some("401")
+                        {
+                            // 时间戳与服务器时间相差过大导致签名失效，使用修正后的时间重新签名
+                            warn!("Request timestamp is skewed, resign and retry");
+                            resigned = true;
+                            resend = true;
+                        } else if let (Some(pool), Some(key)) = (&self.client_config.key_pool, key)
+                        {
+                            if pool.report(key.index, code.as_deref()) {
+                                // 额度不足或无权限，换用下一个密钥；没有其他可用于该接口的密钥时返回这次的响应
+                                tried.push(key.index);
+                                if tried.len() < pool.eligible(subscription_only(&url)) {
+                                    warn!("Key #{} returned {:?}, fail over", key.index, code);
+                                    resend = true;
+                                }
+                            }
+                        }
+                    }
+                    (Err(error), Some(key)) => {
+                        if let Some(pool) = &self.client_config.key_pool {
+                            pool.report_error(key.index, &error.to_string());
+                        }
+                    }
+                    (Err(_), None) => {}
+                }
+                result
+            };
+
+            match result {
+                Ok(mut response) => {
+                    for layer in layers.iter().rev() {
+                        layer.after_response(&request, &mut response, &context);
+                    }
+                    if !resend {
+                        return Ok(response);
+                    }
+                    previous = Some(response);
+                }
+                Err(error) => {
+                    for layer in layers.iter().rev() {
+                        layer.on_error(&request, &error, &context);
+                    }
+                    // 重新发送前额度用完时返回上一次的响应
+                    return match (previous, &error) {
+                        (Some(response), Error::QuotaExhausted { .. }) => {
+                            warn!("{}, stop resending", error);
+                            Ok(response)
+                        }
+                        _ => Err(error),
+                    };
+                }
             }
-            sign.push_str(&format!("{}={}&", key, value));
         }
-        sign.pop();
-        sign.push_str(&self.client_config.private_key);
-        let mut hasher = Md5::new();
-        hasher.update(&sign);
-        let sign = format!("{:x}", hasher.finalize());
+    }
+}
+
+/// 是否为仅限订阅用户的接口
+fn subscription_only(url: &str) -> bool {
+    SUBSCRIPTION_ONLY_PATHS.contains(&url_path(url))
+}
 
-        sign
+/// 响应体中的 `code`
+fn response_code(response: &Response) -> Option<String> {
+    serde_json::from_slice::<Value>(&response.body)
+        .ok()?
+        .get("code")?
+        .as_str()
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_request_api_with_mock_transport() {
+    use crate::transport::MockTransport;
+
+    let transport = MockTransport::new();
+    transport.push_json(serde_json::json!({
+      "code": "200",
+      "updateTime": "2020-06-30T22:00+08:00",
+      "fxLink": "http://hfx.link/2ax1",
+      "now": {
+        "obsTime": "2020-06-30T21:40+08:00",
+        "temp": "24",
+        "feelsLike": "26",
+        "icon": "101",
+        "text": "多云",
+        "wind360": "123",
+        "windDir": "东南风",
+        "windScale": "1",
+        "windSpeed": "3",
+        "humidity": "72",
+        "precip": "0.0",
+        "pressure": "1003",
+        "vis": "16",
+        "cloud": "10",
+        "dew": "21"
+      },
+      "refer": {
+        "sources": ["QWeather"],
+        "license": ["QWeather Developers License"]
+      }
+    }));
+    transport.push_json(serde_json::json!({ "code": "401" }));
+    transport.push_error("connection refused");
+
+    let client = QWeatherClient::with_transport(ClientConfig::new("id", "key"), transport.clone());
+
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Success(resp) => assert_eq!(resp.now.temp, 24.0),
+        other => panic!("unexpected response {:?}", other),
+    }
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Error(code) => assert_eq!(code, "401"),
+        other => panic!("expected error, got {:?}", other),
+    }
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Error(error) => assert!(error.contains("connection refused")),
+        other => panic!("expected error, got {:?}", other),
+    }
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    let request = &requests[0];
+    assert_eq!(request.url, "https://devapi.qweather.com/v7/weather/now");
+    assert_eq!(request.params["location"], "101010100");
+    assert_eq!(request.params["publicid"], "id");
+    assert_eq!(request.params["lang"], "zh");
+    assert!(request.params.contains_key("t"));
+    let mut unsigned = request.params.clone();
+    let sign = unsigned.remove("sign").unwrap();
+    assert_eq!(client.signer().sign(&unsigned), sign);
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_raw_response_and_parse_error() {
+    use crate::transport::MockTransport;
+
+    let body = serde_json::json!({
+      "code": "200",
+      "updateTime": "2020-06-30T22:00+08:00",
+      "fxLink": "http://hfx.link/2ax1",
+      "now": {
+        "obsTime": "2020-06-30T21:40+08:00",
+        "temp": "24",
+        "feelsLike": "26",
+        "icon": "101",
+        "text": "多云",
+        "wind360": "123",
+        "windDir": "东南风",
+        "windScale": "1",
+        "windSpeed": "3",
+        "humidity": "72",
+        "precip": "0.0",
+        "pressure": "1003",
+        "vis": "16",
+        "cloud": "10",
+        "dew": "21"
+      },
+      "refer": {
+        "sources": ["QWeather"],
+        "license": ["QWeather Developers License"]
+      },
+      "newField": { "value": 1 }
+    });
+    let transport = MockTransport::new();
+    let mut response = Response::json(&body);
+    response
+        .headers
+        .insert("x-request-id".to_string(), "abc".to_string());
+    transport.push_response(response);
+    transport.push_json(body.clone());
+    // 字段类型变化
+    let mut changed = body.clone();
+    changed["now"]["temp"] = serde_json::json!({ "value": 24 });
+    transport.push_json(changed.clone());
+    transport.push_json(serde_json::json!({ "code": "204" }));
+
+    let mut config = ClientConfig::new("id", "key");
+    config.coalesce = false;
+    let client = QWeatherClient::with_transport(config, transport);
+
+    match client.weather_now_raw("101010100").await.unwrap() {
+        APIResponse::Success(raw) => {
+            assert_eq!(raw.status, 200);
+            assert_eq!(raw.headers["x-request-id"], "abc");
+            assert_eq!(raw.code(), Some("200"));
+            assert_eq!(raw.body, body);
+        }
+        other => panic!("unexpected response {:?}", other),
+    }
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Success(resp) => {
+            assert_eq!(resp.now.temp, 24.0);
+            assert_eq!(resp.extra["newField"], body["newField"]);
+        }
+        other => panic!("unexpected response {:?}", other),
+    }
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::ParseError { error, body } => {
+            assert!(error.contains("StringOrInt"), "{}", error);
+            assert_eq!(body, changed);
+        }
+        other => panic!("expected parse error, got {:?}", other),
+    }
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Error(code) => assert_eq!(code, "204"),
+        other => panic!("expected error, got {:?}", other),
     }
 }
@@ -0,0 +1,179 @@
+//! 时钟
+//!
+//! 客户端通过 [`Clock`] 获取签名用的时间戳，默认使用系统时间 [`SystemClock`]，测试时可以使用 [`FixedClock`]。
+//!
+//! 开启 [`ClientConfig::skew_correction`](crate::client::ClientConfig::skew_correction) 后，
+//! 客户端根据响应头 `Date` 记录本机与服务器的时间差，并在签名时修正，避免本机时间不准导致签名失效。
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::transport::Response;
+
+/// 时钟
+pub trait Clock: Send + Sync {
+    /// 当前时间
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// 系统时间
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 固定时间，只在调用 [`FixedClock::set`] 或 [`FixedClock::advance`] 时变化，克隆后共享同一时间
+#[derive(Debug, Clone)]
+pub struct FixedClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl FixedClock {
+    /// 固定在 `now`
+    pub fn new(now: DateTime<Utc>) -> Self {
+        FixedClock {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// 设置时间
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.lock() = now;
+    }
+
+    /// 前进 `duration`
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, DateTime<Utc>> {
+        self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+/// 解析响应头 `Date`
+pub(crate) fn server_date(response: &Response) -> Option<DateTime<Utc>> {
+    let date = response.header("date")?;
+
+    DateTime::parse_from_rfc2822(date.trim())
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+#[test]
+fn test_fixed_clock() {
+    let start = DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let clock = FixedClock::new(start);
+    let shared = clock.clone();
+    shared.advance(Duration::seconds(90));
+    assert_eq!(clock.now(), start + Duration::seconds(90));
+    clock.set(start);
+    assert_eq!(shared.now(), start);
+
+    // 锁中毒后仍然可用
+    std::thread::scope(|scope| {
+        let poison = scope.spawn(|| {
+            let _now = clock.lock();
+            panic!("poison the lock");
+        });
+        assert!(poison.join().is_err());
+    });
+    assert!(clock.now.is_poisoned());
+    shared.advance(Duration::seconds(1));
+    assert_eq!(clock.now(), start + Duration::seconds(1));
+
+    let mut response = Response::json(&serde_json::json!({}));
+    assert_eq!(server_date(&response), None);
+    response.headers.insert(
+        "date".to_string(),
+        "Mon, 01 Jan 2024 00:01:30 GMT".to_string(),
+    );
+    assert_eq!(server_date(&response), Some(start + Duration::seconds(90)));
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_skew_correction() {
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        rate_limit::RateLimiter,
+        transport::MockTransport,
+    };
+
+    let server_now = DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    // 服务器只接受与自身时间相差5分钟以内的时间戳
+    let transport = MockTransport::with_handler(move |request| {
+        let t: i64 = request.params["t"].parse().unwrap();
+        let code = if (t - server_now.timestamp()).abs() > 300 {
+            "401"
+        } else {
+            "200"
+        };
+        let mut response = Response::json(&serde_json::json!({ "code": code }));
+        response
+            .headers
+            .insert("date".to_string(), server_now.to_rfc2822());
+        Ok(response)
+    });
+    let url = "https://devapi.qweather.com/v7/weather/now".to_string();
+
+    // 本机时间慢一小时
+    let clock = FixedClock::new(server_now - Duration::hours(1));
+    let mut config = ClientConfig::new("id", "key");
+    config.clock = Arc::new(clock.clone());
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.clone(), Default::default())
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Error(code) if code == "401"));
+    let request = &transport.requests()[0];
+    assert_eq!(request.params["t"], clock.now().timestamp().to_string());
+
+    let mut config = ClientConfig::new("id", "key");
+    config.clock = Arc::new(clock.clone());
+    config.skew_correction = true;
+    config.rate_limiter = Some(RateLimiter::new().with_daily_budget(10));
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    for _ in 0..2 {
+        let resp: APIResponse<serde_json::Value> = client
+            .request_api(url.clone(), Default::default())
+            .await
+            .unwrap();
+        assert!(matches!(resp, APIResponse::Success(_)));
+    }
+    assert_eq!(client.now(), server_now);
+    // 第一次返回401后使用修正后的时间重新签名，之后直接使用修正后的时间
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 4);
+    let timestamps: Vec<_> = requests[1..]
+        .iter()
+        .map(|request| request.params["t"].parse::<i64>().unwrap())
+        .collect();
+    assert_eq!(
+        timestamps,
+        [
+            clock.now().timestamp(),
+            server_now.timestamp(),
+            server_now.timestamp()
+        ]
+    );
+    // 重新签名的请求同样计入每日额度
+    assert_eq!(client.rate_limiter().unwrap().remaining(), Some(7));
+}
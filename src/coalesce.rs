@@ -0,0 +1,245 @@
+//! 合并并发的相同请求
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::watch;
+
+use crate::Error;
+
+type Shared = Arc<dyn Any + Send + Sync>;
+type Key = (String, TypeId);
+
+/// 相同键的并发调用共享同一次执行，结果克隆给所有等待者
+#[derive(Default)]
+pub(crate) struct SingleFlight {
+    calls: Mutex<HashMap<Key, watch::Receiver<Option<Shared>>>>,
+}
+
+/// 执行结束或被取消时移除正在执行的调用
+struct Guard<'a> {
+    flight: &'a SingleFlight,
+    key: Option<Key>,
+}
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.flight.lock().remove(&key);
+        }
+    }
+}
+
+impl SingleFlight {
+    /// 执行 `future`，已有相同键的调用正在执行时等待其结果
+    ///
+    /// 正在执行的调用被取消时，等待者各自重新执行。
+    pub(crate) async fn run<T, F>(&self, key: String, future: F) -> Result<T, Error>
+    where
+        T: Clone + Send + Sync + 'static,
+        F: Future<Output = Result<T, Error>>,
+    {
+        let key = (key, TypeId::of::<Result<T, Error>>());
+        let receiver = {
+            let mut calls = self.lock();
+            match calls.get(&key) {
+                Some(receiver) => Err(receiver.clone()),
+                None => {
+                    let (sender, receiver) = watch::channel(None);
+                    calls.insert(key.clone(), receiver);
+                    Ok(sender)
+                }
+            }
+        };
+
+        let sender = match receiver {
+            Ok(sender) => sender,
+            Err(mut receiver) => {
+                let shared = match receiver.wait_for(Option::is_some).await {
+                    Ok(shared) => shared.clone(),
+                    Err(_) => None,
+                };
+                return match shared {
+                    Some(shared) => shared
+                        .downcast_ref::<Result<T, Error>>()
+                        .expect("coalesced result type")
+                        .clone(),
+                    None => future.await,
+                };
+            }
+        };
+
+        let guard = Guard {
+            flight: self,
+            key: Some(key),
+        };
+        let result = Arc::new(future.await);
+        drop(guard);
+        let _ = sender.send(Some(result.clone()));
+        drop(sender);
+
+        Arc::try_unwrap(result).unwrap_or_else(|result| (*result).clone())
+    }
+
+    /// 正在执行的调用数
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Key, watch::Receiver<Option<Shared>>>> {
+        self.calls.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_single_flight() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let flight = SingleFlight::default();
+    let calls = AtomicUsize::new(0);
+    let (release, released) = watch::channel(false);
+
+    let call = |value: u32| {
+        let mut released = released.clone();
+        let calls = &calls;
+        async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            released.wait_for(|released| *released).await.unwrap();
+            Ok::<_, Error>(value)
+        }
+    };
+    let run = |key: &str, value| flight.run(key.to_string(), call(value));
+    let all = async {
+        let results = tokio::join!(run("a", 1), run("a", 2), run("a", 3), run("b", 4));
+        (
+            results.0.unwrap(),
+            results.1.unwrap(),
+            results.2.unwrap(),
+            results.3.unwrap(),
+        )
+    };
+    let (results, _) = tokio::join!(all, async {
+        tokio::task::yield_now().await;
+        assert_eq!(flight.len(), 2);
+        release.send(true).unwrap();
+    });
+    assert_eq!(results, (1, 1, 1, 4));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(flight.len(), 0);
+
+    // 错误同样返回给所有等待者
+    let (release, released) = watch::channel(false);
+    let failing = || {
+        let mut released = released.clone();
+        async move {
+            released.wait_for(|released| *released).await.unwrap();
+            Err::<u32, _>(Error::QuotaExhausted { limit: 1 })
+        }
+    };
+    let (first, second, _) = tokio::join!(
+        flight.run("a".to_string(), failing()),
+        flight.run("a".to_string(), failing()),
+        async {
+            tokio::task::yield_now().await;
+            release.send(true).unwrap();
+        }
+    );
+    assert!(matches!(first, Err(Error::QuotaExhausted { limit: 1 })));
+    assert!(matches!(second, Err(Error::QuotaExhausted { limit: 1 })));
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_client_coalesce() {
+    use std::{
+        collections::BTreeMap,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use serde::{Deserialize, Deserializer};
+
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        transport::{BoxFuture, Request, Response, Transport},
+    };
+
+    static DECODED: AtomicUsize = AtomicUsize::new(0);
+
+    /// 记录解析次数
+    #[derive(Debug, Clone)]
+    struct Counted;
+
+    impl<'de> Deserialize<'de> for Counted {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            DECODED.fetch_add(1, Ordering::SeqCst);
+            serde::de::IgnoredAny::deserialize(deserializer)?;
+            Ok(Counted)
+        }
+    }
+
+    /// 放行前挂起所有请求
+    struct Gated {
+        body: &'static str,
+        sent: Arc<AtomicUsize>,
+        released: watch::Receiver<bool>,
+    }
+
+    impl Transport for Gated {
+        fn send(&self, _: Request) -> BoxFuture<'_, Result<Response, Error>> {
+            self.sent.fetch_add(1, Ordering::SeqCst);
+            let mut released = self.released.clone();
+            Box::pin(async move {
+                released.wait_for(|released| *released).await.unwrap();
+                Ok(Response {
+                    status: 200,
+                    headers: BTreeMap::new(),
+                    body: self.body.as_bytes().to_vec(),
+                })
+            })
+        }
+    }
+
+    let url = "https://devapi.qweather.com/v7/weather/now";
+    for body in [r#"{"code":"200"}"#, "not json"] {
+        let sent = Arc::new(AtomicUsize::new(0));
+        let (release, released) = watch::channel(false);
+        let transport = Gated {
+            body,
+            sent: sent.clone(),
+            released,
+        };
+        let client = QWeatherClient::with_transport(ClientConfig::new("id", "key"), transport);
+        let call = || client.request_api::<Counted>(url.to_string(), Default::default());
+        let (first, second, third, _) = tokio::join!(call(), call(), call(), async {
+            tokio::task::yield_now().await;
+            release.send(true).unwrap();
+        });
+        assert_eq!(sent.load(Ordering::SeqCst), 1);
+
+        if body == "not json" {
+            // 所有调用方收到同一个错误
+            let errors: Vec<_> = [first, second, third]
+                .into_iter()
+                .map(|result| match result {
+                    Err(Error::Json(error)) => error,
+                    other => panic!("expected json error, got {:?}", other),
+                })
+                .collect();
+            assert!(Arc::ptr_eq(&errors[0], &errors[1]));
+            assert!(Arc::ptr_eq(&errors[0], &errors[2]));
+        } else {
+            // 只解析一次，结果克隆给所有调用方
+            for result in [first, second, third] {
+                assert!(matches!(result, Ok(APIResponse::Success(Counted))));
+            }
+            assert_eq!(DECODED.load(Ordering::SeqCst), 1);
+        }
+    }
+}
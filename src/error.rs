@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+/// SDK错误
+///
+/// 可以克隆，合并的并发请求失败时所有调用方收到同一个错误。
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// HTTP请求错误
+    #[error(transparent)]
+    Http(Arc<reqwest::Error>),
+    /// 传输层错误
+    #[error("transport error: {0}")]
+    Transport(String),
+    /// 响应不是合法的JSON
+    #[error(transparent)]
+    Json(Arc<serde_json::Error>),
+    /// 当日请求额度已用完，请求未发送
+    #[error("daily request budget of {limit} exhausted")]
+    QuotaExhausted {
+        /// 每日请求数上限
+        limit: u32,
+    },
+    /// 密钥池中没有可用于该接口的密钥，例如仅限订阅用户的接口而密钥池中没有订阅密钥，请求未发送
+    #[error("no key in the pool can serve {0}")]
+    NoEligibleKey(String),
+    /// 读写录制文件失败，或回放时找不到匹配的请求
+    #[error("cassette error: {0}")]
+    Cassette(String),
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Http(Arc::new(error))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Json(Arc::new(error))
+    }
+}
@@ -0,0 +1,171 @@
+//! 接口响应样例
+//!
+//! 样例保存在 `tests/fixtures/<接口>/<用例>.json`，接口名称与 [`QWeatherClient`](crate::client::QWeatherClient)
+//! 的方法名相同，覆盖请求成功、无数据（`204`）、错误状态码、英制单位、英文和各种边界情况。
+//! 开启 `test-fixtures` feature 后可以在下游的测试中复用。
+//!
+//! ```
+//! use qweather_sdk::{api::weather::WeatherNowResponse, fixtures, transport::MockTransport};
+//!
+//! let resp: WeatherNowResponse = serde_json::from_str(fixtures::body("weather_now", "success")).unwrap();
+//! assert_eq!(resp.now.text, "多云");
+//!
+//! let transport = MockTransport::new();
+//! transport.push_response(fixtures::get("weather_now", "imperial").unwrap().response());
+//! ```
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::transport::Response;
+
+/// 一个响应样例
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fixture {
+    /// 接口名称，例如 `weather_now`
+    pub endpoint: &'static str,
+    /// 用例名称，例如 `success`、`imperial`
+    pub name: &'static str,
+    /// JSON响应体
+    pub body: &'static str,
+}
+
+impl Fixture {
+    /// 解析后的响应体
+    pub fn json(&self) -> serde_json::Value {
+        serde_json::from_str(self.body).expect("fixture is valid JSON")
+    }
+
+    /// 状态码为200的HTTP响应，错误状态码只出现在响应体的 `code` 字段中
+    pub fn response(&self) -> Response {
+        Response::json(&self.json())
+    }
+}
+
+/// 序列化后重新解析，结果应与原值相同
+///
+/// # Panics
+///
+/// 重新解析失败或结果不同时 panic
+pub fn assert_round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let json = serde_json::to_string(value).unwrap();
+    let decoded: T = serde_json::from_str(&json).unwrap_or_else(|e| panic!("{}: {}", e, json));
+    assert_eq!(&decoded, value);
+}
+
+macro_rules! fixtures {
+    ($($endpoint:literal => [$($name:literal),* $(,)?]),* $(,)?) => {
+        /// 所有响应样例，按接口名称和用例名称排序
+        pub static FIXTURES: &[Fixture] = &[
+            $($(Fixture {
+                endpoint: $endpoint,
+                name: $name,
+                body: include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/tests/fixtures/",
+                    $endpoint,
+                    "/",
+                    $name,
+                    ".json"
+                )),
+            },)*)*
+        ];
+    };
+}
+
+fixtures! {
+    "air_current" => ["cn_mee", "null_health", "success"],
+    "air_daily_forecast" => ["success"],
+    "air_hourly_forecast" => ["success"],
+    "air_station" => ["success"],
+    "geo_city_lookup" => ["english", "not_found", "success"],
+    "geo_city_top" => ["success"],
+    "geo_poi_lookup" => ["success"],
+    "geo_poi_range" => ["success"],
+    "grid_weather_daily_forecast" => ["success"],
+    "grid_weather_hourly_forecast" => ["imperial", "success"],
+    "grid_weather_now" => ["imperial", "success"],
+    "indices_forecast" => ["no_data", "success"],
+    "minutely_precipitation" => ["no_data", "success"],
+    "storm_forecast" => ["empty", "success"],
+    "weather_daily_forecast" => ["english", "imperial", "polar_night", "success"],
+    "weather_hourly_forecast" => ["english", "imperial", "success"],
+    "weather_now" => [
+        "bad_request",
+        "empty_cloud_dew",
+        "english",
+        "forbidden",
+        "imperial",
+        "no_data",
+        "not_found",
+        "over_quota",
+        "server_error",
+        "success",
+        "too_many_requests",
+        "unauthorized",
+    ],
+    "weather_warning" => ["empty", "success"],
+    "weather_warning_city_list" => ["success"],
+}
+
+/// 查找响应样例
+pub fn get(endpoint: &str, name: &str) -> Option<&'static Fixture> {
+    FIXTURES
+        .iter()
+        .find(|fixture| fixture.endpoint == endpoint && fixture.name == name)
+}
+
+/// 某个接口的所有响应样例
+pub fn endpoint(endpoint: &str) -> impl Iterator<Item = &'static Fixture> + '_ {
+    FIXTURES
+        .iter()
+        .filter(move |fixture| fixture.endpoint == endpoint)
+}
+
+/// 响应样例的JSON响应体，样例不存在时 panic
+pub fn body(endpoint: &str, name: &str) -> &'static str {
+    match get(endpoint, name) {
+        Some(fixture) => fixture.body,
+        None => panic!("no fixture {}/{}", endpoint, name),
+    }
+}
+
+/// 解析后的响应样例，样例不存在时 panic
+pub fn json(endpoint: &str, name: &str) -> serde_json::Value {
+    serde_json::from_str(body(endpoint, name)).expect("fixture is valid JSON")
+}
+
+#[test]
+fn test_fixtures_match_directory() {
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let mut files = vec![];
+    for dir in std::fs::read_dir(&root).unwrap() {
+        let dir = dir.unwrap();
+        for file in std::fs::read_dir(dir.path()).unwrap() {
+            let path = file.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                files.push(format!(
+                    "{}/{}",
+                    dir.file_name().to_string_lossy(),
+                    path.file_stem().unwrap().to_string_lossy()
+                ));
+            }
+        }
+    }
+    files.sort();
+
+    let listed: Vec<_> = FIXTURES
+        .iter()
+        .map(|fixture| format!("{}/{}", fixture.endpoint, fixture.name))
+        .collect();
+    assert_eq!(listed, files, "FIXTURES is out of sync with tests/fixtures");
+
+    assert!(get("weather_now", "success").is_some());
+    assert_eq!(endpoint("weather_warning").count(), 2);
+    assert_eq!(
+        json("weather_now", "unauthorized"),
+        serde_json::json!({ "code": "401" })
+    );
+}
@@ -0,0 +1,461 @@
+//! 请求的 `tracing` span 和 `metrics` 指标
+//!
+//! 开启 `tracing` feature 后，每次HTTP请求都会创建名为 `qweather.request` 的 span，
+//! 记录接口路径、地点、第几次请求、HTTP状态码、状态码和耗时。span 中不包含 `publicid`、`sign`
+//! 和私钥。
+//!
+//! 开启 `metrics` feature 后，通过 [`metrics`](https://docs.rs/metrics) 记录以下指标，
+//! `endpoint` 标签为路径模板，例如 `/v7/weather/{n}d`：
+//!
+//! | 指标 | 类型 | 标签 |
+//! | --- | --- | --- |
+//! | `qweather_requests_total` | counter | `endpoint`、`code`（状态码，非JSON响应时为HTTP状态码，传输失败时为 `transport_error`） |
+//! | `qweather_request_duration_seconds` | histogram | `endpoint` |
+//! | `qweather_cache_requests_total` | counter | `endpoint`、`result`（`hit` 或 `miss`） |
+//!
+//! 两个 feature 都未开启时为空操作。
+
+use std::{collections::BTreeMap, future::Future};
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+use std::time::Instant;
+
+#[cfg(feature = "tracing")]
+use tracing::{field::Empty, Instrument};
+
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+use crate::transport::url_path;
+use crate::Error;
+
+/// 单次HTTP请求的 span 和指标
+pub(crate) struct RequestSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "metrics")]
+    endpoint: String,
+    #[cfg(any(feature = "tracing", feature = "metrics"))]
+    started: Instant,
+}
+
+impl RequestSpan {
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn new(url: &str, params: &BTreeMap<String, String>, attempt: u32) -> Self {
+        #[cfg(feature = "tracing")]
+        let span = {
+            let location = match (params.get("latitude"), params.get("longitude")) {
+                (Some(latitude), Some(longitude)) => Some(format!("{},{}", latitude, longitude)),
+                _ => params
+                    .get("location")
+                    .or_else(|| params.get("stormid"))
+                    .cloned(),
+            };
+            tracing::debug_span!(
+                "qweather.request",
+                endpoint = url_path(url),
+                location = location.as_deref(),
+                attempt,
+                status = Empty,
+                code = Empty,
+                latency_ms = Empty,
+                error = Empty,
+            )
+        };
+
+        RequestSpan {
+            #[cfg(feature = "tracing")]
+            span,
+            #[cfg(feature = "metrics")]
+            endpoint: endpoint_template(url),
+            #[cfg(any(feature = "tracing", feature = "metrics"))]
+            started: Instant::now(),
+        }
+    }
+
+    /// 在 span 内执行请求
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        #[cfg(feature = "tracing")]
+        {
+            future.instrument(self.span.clone())
+        }
+        #[cfg(not(feature = "tracing"))]
+        future
+    }
+
+    /// 记录响应
+    #[cfg_attr(
+        not(any(feature = "tracing", feature = "metrics")),
+        allow(unused_variables)
+    )]
+    pub(crate) fn record_response(&self, status: u16, code: Option<&str>) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("status", status);
+            self.span.record("code", code);
+            self.span
+                .record("latency_ms", self.started.elapsed().as_millis() as u64);
+            self.span
+                .in_scope(|| tracing::debug!(status, code, "request finished"));
+        }
+        #[cfg(feature = "metrics")]
+        self.record_metrics(code.map_or_else(|| status.to_string(), str::to_string));
+    }
+
+    /// 记录传输错误
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    pub(crate) fn record_error(&self, error: &Error) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span.record("error", tracing::field::display(error));
+            self.span
+                .record("latency_ms", self.started.elapsed().as_millis() as u64);
+            self.span
+                .in_scope(|| tracing::debug!(%error, "request failed"));
+        }
+        #[cfg(feature = "metrics")]
+        self.record_metrics("transport_error".to_string());
+    }
+
+    #[cfg(feature = "metrics")]
+    fn record_metrics(&self, code: String) {
+        metrics::counter!(
+            "qweather_requests_total",
+            "endpoint" => self.endpoint.clone(),
+            "code" => code
+        )
+        .increment(1);
+        metrics::histogram!(
+            "qweather_request_duration_seconds",
+            "endpoint" => self.endpoint.clone()
+        )
+        .record(self.started.elapsed().as_secs_f64());
+    }
+}
+
+/// 记录缓存命中
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_cache(url: &str, hit: bool) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(
+        "qweather_cache_requests_total",
+        "endpoint" => endpoint_template(url),
+        "result" => if hit { "hit" } else { "miss" }
+    )
+    .increment(1);
+}
+
+/// 请求地址的路径模板，数字替换为占位符，避免标签数量无限增长
+///
+/// 例如 `/v7/weather/3d` 为 `/v7/weather/{n}d`，`/airquality/v1/current/39.90/116.40` 为
+/// `/airquality/v1/current/{lat}/{lon}`，`/airquality/v1/station/P58911` 为 `/airquality/v1/station/{id}`
+#[cfg(feature = "metrics")]
+pub(crate) fn endpoint_template(url: &str) -> String {
+    let mut template = String::new();
+    let mut previous = "";
+    let mut coordinates = ["{lat}", "{lon}"].into_iter();
+    for segment in url_path(url).split('/').skip(1) {
+        template.push('/');
+        let digits = segment.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let suffix = &segment[digits.len()..];
+        if previous == "station" {
+            template.push_str("{id}");
+        } else if segment.parse::<f64>().is_ok() {
+            template.push_str(coordinates.next().unwrap_or("{n}"));
+        } else if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            template.push_str("{n}");
+            template.push_str(suffix);
+        } else {
+            template.push_str(segment);
+        }
+        previous = segment;
+    }
+
+    template
+}
+
+#[cfg(all(test, feature = "tracing"))]
+#[tokio::test]
+async fn test_request_span() {
+    use std::{
+        fmt,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, Mutex,
+        },
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        retry::RetryPolicy,
+        transport::{MockTransport, Response},
+    };
+
+    /// 记录所有 span 和事件字段的订阅者
+    #[derive(Clone, Default)]
+    struct Recorder {
+        next_id: Arc<AtomicU64>,
+        lines: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct Fields<'a>(&'a mut String);
+
+    impl Visit for Fields<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            let mut line = span.metadata().name().to_string();
+            span.record(&mut Fields(&mut line));
+            self.lines.lock().unwrap().push(line);
+            span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst) + 1)
+        }
+
+        fn record(&self, _span: &span::Id, values: &span::Record<'_>) {
+            let mut line = "record".to_string();
+            values.record(&mut Fields(&mut line));
+            self.lines.lock().unwrap().push(line);
+        }
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut line = "event".to_string();
+            event.record(&mut Fields(&mut line));
+            self.lines.lock().unwrap().push(line);
+        }
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let transport = MockTransport::new();
+    transport.push_response(Response {
+        status: 503,
+        ..Response::json(&serde_json::json!({}))
+    });
+    transport.push_json(serde_json::json!({ "code": "200" }));
+    let mut config = ClientConfig::new("secret-public-id", "secret-private-key");
+    config.retry = RetryPolicy {
+        max_attempts: 2,
+        base_delay: std::time::Duration::ZERO,
+        ..Default::default()
+    };
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let mut params = BTreeMap::new();
+    params.insert("location".to_string(), "101010100".to_string());
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(
+            "https://devapi.qweather.com/v7/weather/now".to_string(),
+            params,
+        )
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Success(_)));
+
+    let lines = recorder.lines.lock().unwrap().join("\n");
+    assert!(lines
+        .contains(r#"qweather.request endpoint="/v7/weather/now" location="101010100" attempt=1"#));
+    assert!(lines.contains("attempt=2"));
+    assert!(lines.contains("status=503"));
+    assert!(lines.contains("status=200"));
+    assert!(lines.contains(r#"code="200""#));
+    assert!(lines.contains("latency_ms="));
+    // 不记录密钥和签名
+    assert!(!lines.contains("secret"));
+    for request in transport.requests() {
+        assert!(!lines.contains(&request.params["sign"]));
+    }
+
+    // 真实的传输错误中也不包含请求地址的查询参数
+    recorder.lines.lock().unwrap().clear();
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let mut config = ClientConfig::new("secret-public-id", "secret-private-key");
+    config.api_host = Some(format!("http://127.0.0.1:{}", port));
+    let client = QWeatherClient::with_config(config);
+    let error = match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Error(error) => error,
+        other => panic!("expected error, got {:?}", other),
+    };
+    let lines = recorder.lines.lock().unwrap().join("\n");
+    assert!(lines.contains("error="), "{}", lines);
+    for text in [&error, &lines] {
+        assert!(!text.contains("sign="), "{}", text);
+        assert!(!text.contains("publicid="), "{}", text);
+        assert!(!text.contains("secret"), "{}", text);
+    }
+}
+
+#[cfg(all(test, feature = "metrics"))]
+#[test]
+fn test_request_metrics() {
+    use std::sync::{Arc, Mutex};
+
+    use metrics::{
+        Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+        SharedString, Unit,
+    };
+
+    use crate::{
+        api::APIResponse,
+        cache::ResponseCache,
+        client::{ClientConfig, QWeatherClient},
+        transport::MockTransport,
+    };
+
+    assert_eq!(
+        endpoint_template("https://devapi.qweather.com/v7/weather/7d"),
+        "/v7/weather/{n}d"
+    );
+    assert_eq!(
+        endpoint_template("https://api.qweather.com/v7/grid-weather/24h"),
+        "/v7/grid-weather/{n}h"
+    );
+    assert_eq!(
+        endpoint_template("https://api.qweather.com/airquality/v1/current/39.90/116.40"),
+        "/airquality/v1/current/{lat}/{lon}"
+    );
+    assert_eq!(
+        endpoint_template("https://api.qweather.com/airquality/v1/station/P58911"),
+        "/airquality/v1/station/{id}"
+    );
+    assert_eq!(
+        endpoint_template("https://geoapi.qweather.com/v2/city/lookup"),
+        "/v2/city/lookup"
+    );
+
+    /// 以 `名称{标签}` 为键记录所有指标
+    #[derive(Clone, Default)]
+    struct Recording(Arc<Mutex<BTreeMap<String, f64>>>);
+
+    struct Handle(String, Recording);
+
+    impl CounterFn for Handle {
+        fn increment(&self, value: u64) {
+            *self.1 .0.lock().unwrap().entry(self.0.clone()).or_default() += value as f64;
+        }
+
+        fn absolute(&self, value: u64) {
+            self.1
+                 .0
+                .lock()
+                .unwrap()
+                .insert(self.0.clone(), value as f64);
+        }
+    }
+
+    impl HistogramFn for Handle {
+        fn record(&self, _value: f64) {
+            *self.1 .0.lock().unwrap().entry(self.0.clone()).or_default() += 1.0;
+        }
+    }
+
+    impl Recording {
+        fn handle(&self, key: &Key) -> Arc<Handle> {
+            let labels: Vec<_> = key
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            let name = format!("{}{{{}}}", key.name(), labels.join(","));
+            Arc::new(Handle(name, self.clone()))
+        }
+    }
+
+    impl Recorder for Recording {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.handle(key))
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.handle(key))
+        }
+    }
+
+    let transport = MockTransport::new();
+    transport.push_json(serde_json::json!({ "code": "200" }));
+    transport.push_json(serde_json::json!({ "code": "401" }));
+    transport.push_error("connection refused");
+    let mut config = ClientConfig::new("id", "key");
+    config.cache = Some(ResponseCache::memory(10));
+    let client = QWeatherClient::with_transport(config, transport);
+
+    let recording = Recording::default();
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    metrics::with_local_recorder(&recording, || {
+        runtime.block_on(async {
+            for day in [3, 3, 7, 10] {
+                let _: Result<APIResponse<serde_json::Value>, _> = client
+                    .request_api(
+                        format!("https://devapi.qweather.com/v7/weather/{}d", day),
+                        Default::default(),
+                    )
+                    .await;
+            }
+        })
+    });
+
+    let recorded = recording.0.lock().unwrap().clone();
+    let endpoint = "endpoint=/v7/weather/{n}d";
+    let expected = [
+        (
+            format!("qweather_cache_requests_total{{{},result=hit}}", endpoint),
+            1.0,
+        ),
+        (
+            format!("qweather_cache_requests_total{{{},result=miss}}", endpoint),
+            3.0,
+        ),
+        (
+            format!("qweather_request_duration_seconds{{{}}}", endpoint),
+            3.0,
+        ),
+        (
+            format!("qweather_requests_total{{{},code=200}}", endpoint),
+            1.0,
+        ),
+        (
+            format!("qweather_requests_total{{{},code=401}}", endpoint),
+            1.0,
+        ),
+        (
+            format!(
+                "qweather_requests_total{{{},code=transport_error}}",
+                endpoint
+            ),
+            1.0,
+        ),
+    ];
+    assert_eq!(recorded, expected.into_iter().collect());
+}
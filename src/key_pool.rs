@@ -0,0 +1,618 @@
+//! 多密钥池
+//!
+//! [`KeyPool`] 管理多个账号的密钥，按权重轮询分摊请求。某个密钥返回 `402`（额度不足）或
+//! `403`（无访问权限）时进入冷却期，客户端立即换用下一个密钥重新发送请求。
+//!
+//! 未设置自定义API Host时，每个密钥按是否订阅分别使用 [`WEATHER_API_URL`] 或
+//! [`WEATHER_DEV_API_URL`]；仅限订阅用户的接口只会使用订阅密钥。
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use qweather_sdk::{
+//!     client::ClientConfig,
+//!     key_pool::{ApiKey, KeyPool},
+//! };
+//!
+//! let pool = KeyPool::new([
+//!     ApiKey::new("free-id", "free-key"),
+//!     ApiKey::new("paid-id", "paid-key").subscription().with_weight(3),
+//! ])
+//! .with_cooldown(Duration::from_secs(600));
+//! let mut config = ClientConfig::new("free-id", "free-key");
+//! config.key_pool = Some(pool.clone());
+//!
+//! assert_eq!(pool.stats()[1].public_id, "paid-id");
+//! ```
+
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{signer::Signer, transport::url_path, WEATHER_API_URL, WEATHER_DEV_API_URL};
+
+/// 密钥
+#[derive(Clone)]
+pub struct ApiKey {
+    /// 公钥
+    pub public_id: String,
+    /// 私钥
+    pub private_key: String,
+    /// 是否订阅
+    pub subscription: bool,
+    /// 权重，权重越大分到的请求越多，为0时只在其他密钥都不可用时使用
+    pub weight: u32,
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKey")
+            .field("public_id", &self.public_id)
+            .field("subscription", &self.subscription)
+            .field("weight", &self.weight)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ApiKey {
+    /// 免费订阅的密钥，权重为1
+    pub fn new(public_id: impl ToString, private_key: impl ToString) -> Self {
+        ApiKey {
+            public_id: public_id.to_string(),
+            private_key: private_key.to_string(),
+            subscription: false,
+            weight: 1,
+        }
+    }
+
+    /// 标记为订阅密钥
+    pub fn subscription(mut self) -> Self {
+        self.subscription = true;
+        self
+    }
+
+    /// 设置权重
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// 该密钥使用的API Host
+    pub fn api_host(&self) -> &'static str {
+        if self.subscription {
+            WEATHER_API_URL
+        } else {
+            WEATHER_DEV_API_URL
+        }
+    }
+}
+
+/// 密钥的使用统计
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyStats {
+    /// 公钥
+    pub public_id: String,
+    /// 发送的请求数
+    pub requests: u64,
+    /// 返回错误码或传输失败的请求数
+    pub failures: u64,
+    /// 因 `402`、`403` 进入冷却的次数
+    pub cooldowns: u64,
+    /// 最近一次的错误
+    pub last_error: Option<String>,
+    /// 冷却剩余时间，可用时为 `None`
+    pub cooling_down: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    key: ApiKey,
+    signer: Signer,
+    /// 平滑加权轮询的当前权重
+    current: i64,
+    cooldown_until: Option<Instant>,
+    requests: u64,
+    failures: u64,
+    cooldowns: u64,
+    last_error: Option<String>,
+}
+
+#[derive(Debug)]
+struct State {
+    entries: Vec<Entry>,
+    cooldown: Duration,
+}
+
+/// 本次请求选中的密钥
+#[derive(Debug, Clone)]
+pub(crate) struct SelectedKey {
+    pub(crate) index: usize,
+    pub(crate) signer: Signer,
+    pub(crate) url: String,
+}
+
+/// 多密钥池
+///
+/// 克隆后共享同一份状态，可以保留一份用于查询各密钥的统计。
+#[derive(Debug, Clone)]
+pub struct KeyPool {
+    state: Arc<Mutex<State>>,
+}
+
+impl KeyPool {
+    /// 使用一组密钥创建密钥池，默认冷却时间为1小时
+    pub fn new(keys: impl IntoIterator<Item = ApiKey>) -> Self {
+        let entries = keys
+            .into_iter()
+            .map(|key| Entry {
+                signer: Signer::new(&key.public_id, &key.private_key),
+                key,
+                current: 0,
+                cooldown_until: None,
+                requests: 0,
+                failures: 0,
+                cooldowns: 0,
+                last_error: None,
+            })
+            .collect();
+
+        KeyPool {
+            state: Arc::new(Mutex::new(State {
+                entries,
+                cooldown: Duration::from_secs(3600),
+            })),
+        }
+    }
+
+    /// 设置返回 `402`、`403` 后的冷却时间
+    pub fn with_cooldown(self, cooldown: Duration) -> Self {
+        self.lock().cooldown = cooldown;
+        self
+    }
+
+    /// 密钥数量
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// 各密钥的使用统计，顺序与创建时相同
+    pub fn stats(&self) -> Vec<KeyStats> {
+        let now = Instant::now();
+        self.lock()
+            .entries
+            .iter()
+            .map(|entry| KeyStats {
+                public_id: entry.key.public_id.clone(),
+                requests: entry.requests,
+                failures: entry.failures,
+                cooldowns: entry.cooldowns,
+                last_error: entry.last_error.clone(),
+                cooling_down: entry
+                    .cooldown_until
+                    .and_then(|until| until.checked_duration_since(now))
+                    .filter(|remaining| !remaining.is_zero()),
+            })
+            .collect()
+    }
+
+    /// 可用于请求的密钥数量，`subscription_only` 为真时只计算订阅密钥，冷却中的密钥同样计入
+    pub(crate) fn eligible(&self, subscription_only: bool) -> usize {
+        self.lock()
+            .entries
+            .iter()
+            .filter(|entry| !subscription_only || entry.key.subscription)
+            .count()
+    }
+
+    /// 为请求选择密钥，跳过本次请求已经失败的密钥
+    ///
+    /// `subscription_only` 为真时只选择订阅密钥；`rewrite_host` 为真时将请求地址的Host替换为密钥对应的Host。
+    pub(crate) fn select(
+        &self,
+        url: &str,
+        subscription_only: bool,
+        rewrite_host: bool,
+        tried: &[usize],
+    ) -> Option<SelectedKey> {
+        self.select_at(url, subscription_only, rewrite_host, tried, Instant::now())
+    }
+
+    fn select_at(
+        &self,
+        url: &str,
+        subscription_only: bool,
+        rewrite_host: bool,
+        tried: &[usize],
+        now: Instant,
+    ) -> Option<SelectedKey> {
+        let mut state = self.lock();
+        let candidates: Vec<usize> = state
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(index, entry)| {
+                !tried.contains(index) && (!subscription_only || entry.key.subscription)
+            })
+            .map(|(index, _)| index)
+            .collect();
+        let available: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|&index| {
+                state.entries[index]
+                    .cooldown_until
+                    .is_none_or(|until| until <= now)
+            })
+            .collect();
+        // 所有密钥都在冷却时，使用最早结束冷却的密钥
+        let index = if available.is_empty() {
+            candidates
+                .into_iter()
+                .min_by_key(|&index| state.entries[index].cooldown_until)?
+        } else {
+            smooth_weighted(&mut state.entries, &available)
+        };
+
+        let entry = &mut state.entries[index];
+        entry.requests += 1;
+        let url = if rewrite_host {
+            format!("{}{}", entry.key.api_host(), url_path(url))
+        } else {
+            url.to_string()
+        };
+
+        Some(SelectedKey {
+            index,
+            signer: entry.signer.clone(),
+            url,
+        })
+    }
+
+    /// 记录请求结果，`code` 为响应中的错误码，返回该密钥是否进入冷却
+    pub(crate) fn report(&self, index: usize, code: Option<&str>) -> bool {
+        self.report_at(index, code, Instant::now())
+    }
+
+    fn report_at(&self, index: usize, code: Option<&str>, now: Instant) -> bool {
+        let mut state = self.lock();
+        let cooldown = state.cooldown;
+        let Some(entry) = state.entries.get_mut(index) else {
+            return false;
+        };
+        match code {
+            None | Some("200") | Some("204") => false,
+            Some(code) => {
+                entry.failures += 1;
+                entry.last_error = Some(code.to_string());
+                if code == "402" || code == "403" {
+                    entry.cooldowns += 1;
+                    entry.cooldown_until = Some(now + cooldown);
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// 记录传输失败
+    pub(crate) fn report_error(&self, index: usize, error: &str) {
+        if let Some(entry) = self.lock().entries.get_mut(index) {
+            entry.failures += 1;
+            entry.last_error = Some(error.to_string());
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 平滑加权轮询，权重为0的密钥只在所有候选的权重都为0时按顺序使用
+fn smooth_weighted(entries: &mut [Entry], available: &[usize]) -> usize {
+    let total: i64 = available
+        .iter()
+        .map(|&index| entries[index].key.weight as i64)
+        .sum();
+    if total == 0 {
+        return available[0];
+    }
+    for &index in available {
+        entries[index].current += entries[index].key.weight as i64;
+    }
+    let best = available
+        .iter()
+        .copied()
+        .rev()
+        .max_by_key(|&index| entries[index].current)
+        .expect("available is not empty");
+    entries[best].current -= total;
+
+    best
+}
+
+#[test]
+fn test_key_pool_selection() {
+    let pool = KeyPool::new([
+        ApiKey::new("a", "ka").with_weight(2),
+        ApiKey::new("b", "kb").subscription(),
+        ApiKey::new("c", "kc").with_weight(0),
+    ])
+    .with_cooldown(Duration::from_secs(60));
+    let now = Instant::now();
+    let url = "https://devapi.qweather.com/v7/weather/now";
+    let select = |subscription_only, tried: &[usize], now| {
+        pool.select_at(url, subscription_only, true, tried, now)
+            .unwrap()
+    };
+
+    // 平滑加权轮询
+    let order: Vec<_> = (0..6).map(|_| select(false, &[], now).index).collect();
+    assert_eq!(order, [0, 1, 0, 0, 1, 0]);
+    let selected = select(false, &[], now);
+    assert_eq!(selected.url, "https://devapi.qweather.com/v7/weather/now");
+    assert_eq!(selected.signer.public_id(), "a");
+    let selected = select(false, &[], now);
+    assert_eq!(selected.url, "https://api.qweather.com/v7/weather/now");
+    // 仅限订阅
+    assert_eq!(select(true, &[], now).index, 1);
+    assert!(pool.select_at(url, true, true, &[1], now).is_none());
+    // 跳过已失败的密钥，权重为0的密钥只在其他密钥都不可用时使用
+    assert_eq!(select(false, &[0, 1], now).index, 2);
+
+    // 冷却
+    assert!(!pool.report_at(0, Some("200"), now));
+    assert!(!pool.report_at(0, Some("429"), now));
+    assert!(pool.report_at(0, Some("402"), now));
+    assert_eq!(select(false, &[], now).index, 1);
+    assert_eq!(select(false, &[], now).index, 1);
+    assert!(pool.report_at(1, Some("403"), now));
+    assert_eq!(select(false, &[], now).index, 2);
+    assert!(pool.report_at(2, Some("402"), now + Duration::from_secs(1)));
+    // 全部冷却时使用最早结束冷却的密钥
+    assert_eq!(select(false, &[], now).index, 0);
+    let later = now + Duration::from_secs(60);
+    assert_eq!(select(false, &[], later).index, 0);
+
+    let stats = pool.stats();
+    assert_eq!(stats[0].public_id, "a");
+    assert_eq!(stats[0].failures, 2);
+    assert_eq!(stats[0].cooldowns, 1);
+    assert_eq!(stats[0].last_error.as_deref(), Some("402"));
+    assert_eq!(stats[2].requests, 2);
+    pool.report_error(2, "connection refused");
+    assert_eq!(
+        pool.stats()[2].last_error.as_deref(),
+        Some("connection refused")
+    );
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_client_key_failover() {
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        rate_limit::RateLimiter,
+        transport::{MockTransport, Response},
+    };
+
+    // 免费密钥额度用完
+    let transport = MockTransport::with_handler(|request| {
+        let code = match request.params["publicid"].as_str() {
+            "free" => "402",
+            _ => "200",
+        };
+        Ok(Response::json(&serde_json::json!({ "code": code })))
+    });
+    let pool = KeyPool::new([
+        ApiKey::new("free", "free-key"),
+        ApiKey::new("paid", "paid-key").subscription(),
+    ]);
+    let mut config = ClientConfig::new("id", "key");
+    config.key_pool = Some(pool.clone());
+    let client = QWeatherClient::with_transport(config, transport.clone());
+
+    for _ in 0..2 {
+        let resp: APIResponse<serde_json::Value> = client
+            .request_api(
+                "https://devapi.qweather.com/v7/weather/now".to_string(),
+                Default::default(),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(resp, APIResponse::Success(_)));
+    }
+    let requests = transport.requests();
+    let sent: Vec<_> = requests
+        .iter()
+        .map(|request| (request.params["publicid"].as_str(), request.url.as_str()))
+        .collect();
+    assert_eq!(
+        sent,
+        [
+            ("free", "https://devapi.qweather.com/v7/weather/now"),
+            ("paid", "https://api.qweather.com/v7/weather/now"),
+            ("paid", "https://api.qweather.com/v7/weather/now"),
+        ]
+    );
+    let mut unsigned = requests[1].params.clone();
+    let sign = unsigned.remove("sign").unwrap();
+    assert_eq!(Signer::new("paid", "paid-key").sign(&unsigned), sign);
+    let stats = client.key_pool().unwrap().stats();
+    assert_eq!(stats[0].cooldowns, 1);
+    assert!(stats[0].cooling_down.is_some());
+    assert_eq!(stats[1].requests, 2);
+
+    // 仅限订阅用户的接口不使用免费密钥
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(
+            "https://api.qweather.com/v7/tropical/storm-list".to_string(),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Success(_)));
+    assert_eq!(transport.requests()[3].params["publicid"], "paid");
+
+    // 换用密钥的请求计入每日额度，额度用完时返回上一个密钥的响应
+    let transport = MockTransport::with_handler(|request| {
+        let code = match request.params["publicid"].as_str() {
+            "paid" => "200",
+            _ => "402",
+        };
+        Ok(Response::json(&serde_json::json!({ "code": code })))
+    });
+    let limiter = RateLimiter::new().with_daily_budget(3);
+    let mut config = ClientConfig::new("id", "key");
+    config.key_pool = Some(KeyPool::new([
+        ApiKey::new("free", "free-key"),
+        ApiKey::new("other", "other-key"),
+        ApiKey::new("paid", "paid-key").subscription(),
+    ]));
+    config.rate_limiter = Some(limiter.clone());
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let url = "https://devapi.qweather.com/v7/weather/now".to_string();
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.clone(), Default::default())
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Success(_)));
+    assert_eq!(transport.requests().len(), 3);
+    assert_eq!(limiter.remaining(), Some(0));
+
+    let limiter = RateLimiter::new().with_daily_budget(2);
+    let mut config = ClientConfig::new("id", "key");
+    config.key_pool = Some(KeyPool::new([
+        ApiKey::new("free", "free-key"),
+        ApiKey::new("other", "other-key"),
+        ApiKey::new("paid", "paid-key").subscription(),
+    ]));
+    config.rate_limiter = Some(limiter.clone());
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let resp: APIResponse<serde_json::Value> =
+        client.request_api(url, Default::default()).await.unwrap();
+    assert!(matches!(resp, APIResponse::Error(code) if code == "402"));
+    assert_eq!(transport.requests().len(), 5);
+    assert_eq!(limiter.remaining(), Some(0));
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_client_key_pool_subscription_only() {
+    use crate::{
+        client::{ClientConfig, QWeatherClient},
+        transport::{MockTransport, Response},
+    };
+
+    for subscription in [false, true] {
+        let transport = MockTransport::with_handler(|_| {
+            Ok(Response::json(&serde_json::json!({ "code": "200" })))
+        });
+        let pool = KeyPool::new([
+            ApiKey::new("free", "free-key"),
+            ApiKey::new("paid", "paid-key").subscription(),
+        ]);
+        let mut config = ClientConfig::new("id", "key");
+        config.subscription = subscription;
+        config.coalesce = false;
+        config.key_pool = Some(pool);
+        let client = QWeatherClient::with_transport(config, transport.clone());
+
+        for _ in 0..2 {
+            client.storm_forecast_raw("NP2018").await.unwrap();
+        }
+        for _ in 0..2 {
+            client.weather_now_raw("101010100").await.unwrap();
+        }
+
+        let sent: Vec<_> = transport
+            .requests()
+            .iter()
+            .map(|request| (request.params["publicid"].clone(), request.url.clone()))
+            .collect();
+        let storm = "https://api.qweather.com/v7/tropical/storm-forecast";
+        assert_eq!(
+            sent,
+            [
+                ("paid".to_string(), storm.to_string()),
+                ("paid".to_string(), storm.to_string()),
+                (
+                    "free".to_string(),
+                    "https://devapi.qweather.com/v7/weather/now".to_string()
+                ),
+                (
+                    "paid".to_string(),
+                    "https://api.qweather.com/v7/weather/now".to_string()
+                ),
+            ],
+            "subscription = {}",
+            subscription
+        );
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_client_key_pool_no_eligible_key() {
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        transport::{MockTransport, Response},
+        Error,
+    };
+
+    // 唯一的订阅密钥额度用完时返回它的响应，不换用免费密钥或配置中的密钥
+    let transport = MockTransport::with_handler(|request| {
+        let code = match request.params["publicid"].as_str() {
+            "paid" => "402",
+            _ => "200",
+        };
+        Ok(Response::json(&serde_json::json!({ "code": code })))
+    });
+    let mut config = ClientConfig::new("id", "key");
+    config.key_pool = Some(KeyPool::new([
+        ApiKey::new("free", "free-key"),
+        ApiKey::new("paid", "paid-key").subscription(),
+    ]));
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let url = "https://api.qweather.com/v7/tropical/storm-list";
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.to_string(), Default::default())
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Error(code) if code == "402"));
+    let sent: Vec<_> = transport
+        .requests()
+        .iter()
+        .map(|request| (request.params["publicid"].clone(), request.url.clone()))
+        .collect();
+    assert_eq!(sent, [("paid".to_string(), url.to_string())]);
+
+    // 冷却中的订阅密钥仍会被使用
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.to_string(), Default::default())
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Error(code) if code == "402"));
+    assert_eq!(transport.requests().len(), 2);
+    assert_eq!(transport.requests()[1].params["publicid"], "paid");
+
+    // 密钥池中没有订阅密钥时不发送请求
+    let transport = MockTransport::new();
+    let mut config = ClientConfig::new("id", "key");
+    config.key_pool = Some(KeyPool::new([ApiKey::new("free", "free-key")]));
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let result = client
+        .request_api::<serde_json::Value>(url.to_string(), Default::default())
+        .await;
+    assert!(matches!(result, Err(Error::NoEligibleKey(path)) if path == "/v7/tropical/storm-list"));
+    assert!(transport.requests().is_empty());
+}
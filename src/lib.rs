@@ -15,6 +15,28 @@ pub static WEATHER_API_URL: &str = "https://api.qweather.com";
 pub static WEATHER_DEV_API_URL: &str = "https://devapi.qweather.com";
 
 pub mod api;
+pub mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod cache;
+pub mod cassette;
 pub mod client;
+pub mod clock;
+mod coalesce;
+mod error;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod fixtures;
+mod instrument;
+pub mod key_pool;
+pub mod middleware;
+pub mod rate_limit;
+pub mod retry;
+pub mod signer;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod transport;
+pub mod watch;
 
-pub type APIResult<T> = Result<APIResponse<T>, reqwest::Error>;
+pub use error::Error;
+
+pub type APIResult<T> = Result<APIResponse<T>, Error>;
@@ -0,0 +1,310 @@
+//! 请求中间件
+//!
+//! 中间件在每次发送HTTP请求前后被调用，包括重试、时间戳偏差重新签名和换用密钥重新发送的每一次请求，
+//! 命中缓存时不会调用。`before_request` 按注册顺序调用，`after_response` 和 `on_error` 按相反的顺序调用。
+//!
+//! `before_request` 返回响应时请求不会签名和发送，也不计入限流和额度，之后的中间件不再调用，
+//! 之前的中间件照常收到 `after_response`，可以用来实现缓存或模拟响应。
+//!
+//! ```
+//! use qweather_sdk::{
+//!     client::ClientConfig,
+//!     middleware::{Context, Middleware},
+//!     transport::{Request, Response},
+//! };
+//!
+//! struct Tenant(&'static str);
+//!
+//! impl Middleware for Tenant {
+//!     fn before_request(&self, request: &mut Request, context: &mut Context) -> Option<Response> {
+//!         request.headers.insert("x-tenant".to_string(), self.0.to_string());
+//!         context.extensions.insert("tenant".to_string(), self.0.to_string());
+//!         None
+//!     }
+//!
+//!     fn after_response(&self, _request: &Request, response: &mut Response, context: &Context) {
+//!         println!("{} {:?} {}", context.extensions["tenant"], context.elapsed(), response.status);
+//!     }
+//! }
+//!
+//! let config = ClientConfig::new("id", "key").with_middleware(Tenant("acme"));
+//! ```
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    rate_limit::EndpointFamily,
+    transport::{Request, Response},
+    Error,
+};
+
+/// 单次HTTP请求的上下文
+#[derive(Debug, Clone)]
+pub struct Context {
+    /// 接口类别
+    pub family: EndpointFamily,
+    /// 第几次请求，从 1 开始，按重试策略重试时递增
+    pub attempt: u32,
+    /// 本次请求中第几次发送，从 1 开始，时间戳偏差重新签名或换用密钥重新发送时递增
+    pub send: u32,
+    /// 开始发送的时间
+    pub started: Instant,
+    /// 中间件之间传递的自定义数据
+    pub extensions: BTreeMap<String, String>,
+}
+
+impl Context {
+    pub(crate) fn new(url: &str, attempt: u32, send: u32) -> Self {
+        Context {
+            family: EndpointFamily::from_url(url),
+            attempt,
+            send,
+            started: Instant::now(),
+            extensions: BTreeMap::new(),
+        }
+    }
+
+    /// 已经过的时间，在 `after_response` 和 `on_error` 中为请求耗时
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
+
+/// 请求中间件，所有方法默认不做任何事
+pub trait Middleware: Send + Sync {
+    /// 签名前调用，可以添加请求头和查询参数，添加的查询参数会参与签名
+    ///
+    /// 返回 `Some` 时使用该响应代替发送请求，`request` 不会签名。
+    fn before_request(&self, request: &mut Request, context: &mut Context) -> Option<Response> {
+        let _ = (request, context);
+        None
+    }
+
+    /// 收到响应后调用，`request` 为签名后实际发送的请求，被中间件直接返回响应时为未签名的请求
+    fn after_response(&self, request: &Request, response: &mut Response, context: &Context) {
+        let _ = (request, response, context);
+    }
+
+    /// 传输失败时调用
+    fn on_error(&self, request: &Request, error: &Error, context: &Context) {
+        let _ = (request, error, context);
+    }
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_middleware() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        transport::MockTransport,
+    };
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Middleware for Recorder {
+        fn before_request(&self, request: &mut Request, context: &mut Context) -> Option<Response> {
+            request
+                .params
+                .insert("tenant".to_string(), "acme".to_string());
+            request
+                .headers
+                .insert(format!("x-{}", self.name), context.attempt.to_string());
+            context
+                .extensions
+                .insert(self.name.to_string(), "seen".to_string());
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{} before", self.name));
+            None
+        }
+
+        fn after_response(&self, request: &Request, response: &mut Response, context: &Context) {
+            assert!(request.params.contains_key("sign"));
+            assert_eq!(context.extensions[self.name], "seen");
+            response
+                .headers
+                .insert("x-seen".to_string(), self.name.to_string());
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{} after {:?}", self.name, context.family));
+        }
+
+        fn on_error(&self, _request: &Request, error: &Error, _context: &Context) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("{} error {}", self.name, error));
+        }
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let first = Recorder {
+        name: "first",
+        events: events.clone(),
+    };
+    let second = Recorder {
+        name: "second",
+        events: events.clone(),
+    };
+    let transport = MockTransport::new();
+    transport.push_json(serde_json::json!({ "code": "200" }));
+    transport.push_error("connection refused");
+    let config = ClientConfig::new("id", "key")
+        .with_middleware(first)
+        .with_middleware(second);
+    let client = QWeatherClient::with_transport(config, transport.clone());
+
+    let url = "https://devapi.qweather.com/v7/warning/now".to_string();
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.clone(), Default::default())
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Success(_)));
+    let resp: APIResponse<serde_json::Value> =
+        client.request_api(url, Default::default()).await.unwrap();
+    assert!(matches!(resp, APIResponse::Error(_)));
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        [
+            "first before",
+            "second before",
+            "second after Warning",
+            "first after Warning",
+            "first before",
+            "second before",
+            "second error transport error: connection refused",
+            "first error transport error: connection refused",
+        ]
+    );
+
+    let request = &transport.requests()[0];
+    assert_eq!(request.headers["x-first"], "1");
+    assert_eq!(request.headers["x-second"], "1");
+    // 中间件添加的参数参与签名
+    let mut unsigned = request.params.clone();
+    let sign = unsigned.remove("sign").unwrap();
+    assert_eq!(unsigned["tenant"], "acme");
+    assert_eq!(client.signer().sign(&unsigned), sign);
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_middleware_short_circuit_and_resend() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        key_pool::{ApiKey, KeyPool},
+        rate_limit::RateLimiter,
+        transport::MockTransport,
+    };
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Middleware for Recorder {
+        fn before_request(
+            &self,
+            _request: &mut Request,
+            context: &mut Context,
+        ) -> Option<Response> {
+            self.0
+                .lock()
+                .unwrap()
+                .push(format!("before {}", context.send));
+            None
+        }
+
+        fn after_response(&self, request: &Request, response: &mut Response, context: &Context) {
+            let signed = match request.params.contains_key("sign") {
+                true => request.params["publicid"].as_str(),
+                false => "unsigned",
+            };
+            self.0.lock().unwrap().push(format!(
+                "after {} {} {}",
+                context.send,
+                signed,
+                String::from_utf8_lossy(&response.body)
+            ));
+        }
+    }
+
+    /// 直接返回响应，不发送请求
+    struct Canned;
+
+    impl Middleware for Canned {
+        fn before_request(
+            &self,
+            _request: &mut Request,
+            _context: &mut Context,
+        ) -> Option<Response> {
+            Some(Response::json(&serde_json::json!({ "code": "200" })))
+        }
+
+        fn after_response(&self, _request: &Request, _response: &mut Response, _context: &Context) {
+            panic!("short-circuiting middleware should not see its own response");
+        }
+    }
+
+    let url = "https://devapi.qweather.com/v7/weather/now".to_string();
+
+    // 短路：不签名、不发送、不计入额度，之前的中间件照常收到响应
+    let recorder = Recorder::default();
+    let transport = MockTransport::new();
+    let limiter = RateLimiter::new().with_daily_budget(1);
+    let mut config = ClientConfig::new("id", "key")
+        .with_middleware(recorder.clone())
+        .with_middleware(Canned);
+    config.rate_limiter = Some(limiter.clone());
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.clone(), Default::default())
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Success(_)));
+    assert!(transport.requests().is_empty());
+    assert_eq!(limiter.remaining(), Some(1));
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        ["before 1", r#"after 1 unsigned {"code":"200"}"#]
+    );
+
+    // 换用密钥重新发送时每次发送都调用中间件
+    let recorder = Recorder::default();
+    let transport = MockTransport::new();
+    transport.push_json(serde_json::json!({ "code": "402" }));
+    transport.push_json(serde_json::json!({ "code": "200" }));
+    let mut config = ClientConfig::new("id", "key").with_middleware(recorder.clone());
+    config.key_pool = Some(KeyPool::new([
+        ApiKey::new("free", "free-key"),
+        ApiKey::new("paid", "paid-key").subscription(),
+    ]));
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let resp: APIResponse<serde_json::Value> =
+        client.request_api(url, Default::default()).await.unwrap();
+    assert!(matches!(resp, APIResponse::Success(_)));
+    assert_eq!(transport.requests().len(), 2);
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        [
+            "before 1",
+            r#"after 1 free {"code":"402"}"#,
+            "before 2",
+            r#"after 2 paid {"code":"200"}"#,
+        ]
+    );
+}
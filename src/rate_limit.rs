@@ -0,0 +1,408 @@
+//! 客户端限流和每日请求额度
+//!
+//! [`RateLimiter`] 按接口类别使用令牌桶限流，超出速率的请求会等待；设置了每日额度时，
+//! 额度用完后请求在本地直接返回 [`Error::QuotaExhausted`]，不会消耗服务端的请求量。
+//!
+//! ```
+//! use qweather_sdk::{
+//!     client::ClientConfig,
+//!     rate_limit::{EndpointFamily, RateLimit, RateLimiter},
+//! };
+//!
+//! let limiter = RateLimiter::new()
+//!     .with_default_limit(RateLimit::per_minute(60))
+//!     .with_limit(EndpointFamily::Geo, RateLimit::per_minute(10))
+//!     .with_daily_budget(1000);
+//! let mut config = ClientConfig::new("id", "key");
+//! config.rate_limiter = Some(limiter.clone());
+//!
+//! assert_eq!(limiter.remaining(), Some(1000));
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use chrono::{FixedOffset, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{transport::url_path, Error};
+
+/// 接口类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EndpointFamily {
+    /// 天气预报、格点天气、分钟级降水、天气指数、台风等
+    Weather,
+    /// 城市搜索、POI搜索
+    Geo,
+    /// 空气质量
+    Air,
+    /// 天气灾害预警
+    Warning,
+}
+
+impl EndpointFamily {
+    /// 所有类别
+    pub const ALL: [EndpointFamily; 4] = [
+        EndpointFamily::Weather,
+        EndpointFamily::Geo,
+        EndpointFamily::Air,
+        EndpointFamily::Warning,
+    ];
+
+    /// 根据请求地址判断接口类别
+    pub fn from_url(url: &str) -> Self {
+        let path = url_path(url);
+        if path.starts_with("/v2/city/") || path.starts_with("/v2/poi/") {
+            EndpointFamily::Geo
+        } else if path.starts_with("/airquality/") || path.starts_with("/v7/air/") {
+            EndpointFamily::Air
+        } else if path.starts_with("/v7/warning/") {
+            EndpointFamily::Warning
+        } else {
+            EndpointFamily::Weather
+        }
+    }
+}
+
+/// 速率限制，允许突发 `requests` 个请求，之后以 `requests / per` 的速率恢复
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// 时间窗口内的请求数
+    pub requests: u32,
+    /// 时间窗口
+    pub per: Duration,
+}
+
+impl RateLimit {
+    /// 每秒 `requests` 个请求
+    pub fn per_second(requests: u32) -> Self {
+        RateLimit {
+            requests,
+            per: Duration::from_secs(1),
+        }
+    }
+
+    /// 每分钟 `requests` 个请求
+    pub fn per_minute(requests: u32) -> Self {
+        RateLimit {
+            requests,
+            per: Duration::from_secs(60),
+        }
+    }
+}
+
+/// 每日请求额度，可以序列化保存，重启后通过 [`RateLimiter::with_budget`] 恢复
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotaBudget {
+    /// 每日请求数上限
+    pub limit: u32,
+    /// 当日已使用的请求数
+    pub used: u32,
+    /// 额度所属日期（北京时间）
+    pub date: NaiveDate,
+}
+
+impl QuotaBudget {
+    /// 创建当日的额度
+    pub fn new(limit: u32) -> Self {
+        QuotaBudget {
+            limit,
+            used: 0,
+            date: today(),
+        }
+    }
+
+    /// 剩余请求数
+    pub fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.used)
+    }
+
+    /// 跨日后重置已使用的请求数
+    fn roll_over(&mut self, today: NaiveDate) {
+        if self.date != today {
+            self.date = today;
+            self.used = 0;
+        }
+    }
+}
+
+/// 令牌桶
+#[derive(Debug)]
+struct Bucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Self {
+        Bucket {
+            limit,
+            tokens: limit.requests as f64,
+            updated: Instant::now(),
+        }
+    }
+
+    /// 取出一个令牌，返回需要等待的时间
+    ///
+    /// 令牌不足时先预支，并发的请求会依次排队等待
+    fn take(&mut self, now: Instant) -> Duration {
+        let capacity = self.limit.requests.max(1) as f64;
+        let rate = capacity / self.limit.per.as_secs_f64().max(f64::EPSILON);
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(capacity);
+        self.updated = now;
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / rate)
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    buckets: HashMap<EndpointFamily, Bucket>,
+    budget: Option<QuotaBudget>,
+}
+
+/// 客户端限流器
+///
+/// 克隆后共享同一份状态，可以保留一份用于查询剩余额度。
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<State>>,
+}
+
+impl RateLimiter {
+    /// 创建不限流的限流器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置某一类接口的速率限制
+    pub fn with_limit(self, family: EndpointFamily, limit: RateLimit) -> Self {
+        self.lock().buckets.insert(family, Bucket::new(limit));
+        self
+    }
+
+    /// 为所有类别的接口分别设置相同的速率限制
+    pub fn with_default_limit(self, limit: RateLimit) -> Self {
+        EndpointFamily::ALL
+            .into_iter()
+            .fold(self, |limiter, family| limiter.with_limit(family, limit))
+    }
+
+    /// 设置每日请求额度
+    pub fn with_daily_budget(self, limit: u32) -> Self {
+        self.with_budget(QuotaBudget::new(limit))
+    }
+
+    /// 恢复保存的每日请求额度
+    pub fn with_budget(self, budget: QuotaBudget) -> Self {
+        self.lock().budget = Some(budget);
+        self
+    }
+
+    /// 当前的每日请求额度，可以序列化保存
+    pub fn budget(&self) -> Option<QuotaBudget> {
+        let mut state = self.lock();
+        let budget = state.budget.as_mut()?;
+        budget.roll_over(today());
+
+        Some(budget.clone())
+    }
+
+    /// 当日剩余请求数，未设置每日额度时返回 `None`
+    pub fn remaining(&self) -> Option<u32> {
+        self.budget().map(|budget| budget.remaining())
+    }
+
+    /// 申请一次请求，返回需要等待的时间，额度用完时返回错误
+    pub(crate) fn acquire(&self, family: EndpointFamily) -> Result<Duration, Error> {
+        self.acquire_at(family, Instant::now(), today())
+    }
+
+    fn acquire_at(
+        &self,
+        family: EndpointFamily,
+        now: Instant,
+        today: NaiveDate,
+    ) -> Result<Duration, Error> {
+        let mut state = self.lock();
+        if let Some(budget) = state.budget.as_mut() {
+            budget.roll_over(today);
+            if budget.remaining() == 0 {
+                return Err(Error::QuotaExhausted {
+                    limit: budget.limit,
+                });
+            }
+            budget.used += 1;
+        }
+
+        Ok(state
+            .buckets
+            .get_mut(&family)
+            .map_or(Duration::ZERO, |bucket| bucket.take(now)))
+    }
+
+    /// 服务端返回额度不足时，将当日额度标记为用完
+    pub(crate) fn exhaust(&self) {
+        if let Some(budget) = self.lock().budget.as_mut() {
+            budget.used = budget.limit;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// 北京时间的当前日期，和风天气按北京时间统计每日请求量
+fn today() -> NaiveDate {
+    let offset = FixedOffset::east_opt(8 * 3600).expect("valid offset");
+
+    Utc::now().with_timezone(&offset).date_naive()
+}
+
+#[test]
+fn test_endpoint_family() {
+    let cases = [
+        (
+            "https://devapi.qweather.com/v7/weather/now",
+            EndpointFamily::Weather,
+        ),
+        (
+            "https://api.qweather.com/v7/grid-weather/24h",
+            EndpointFamily::Weather,
+        ),
+        (
+            "https://geoapi.qweather.com/v2/city/lookup",
+            EndpointFamily::Geo,
+        ),
+        ("http://127.0.0.1:8080/v2/poi/range", EndpointFamily::Geo),
+        (
+            "https://api.qweather.com/airquality/v1/current/39.9/116.4",
+            EndpointFamily::Air,
+        ),
+        (
+            "https://devapi.qweather.com/v7/warning/now",
+            EndpointFamily::Warning,
+        ),
+        ("/v7/warning/list", EndpointFamily::Warning),
+    ];
+    for (url, family) in cases {
+        assert_eq!(EndpointFamily::from_url(url), family, "{}", url);
+    }
+}
+
+#[test]
+fn test_rate_limiter() {
+    let limiter = RateLimiter::new().with_limit(EndpointFamily::Geo, RateLimit::per_second(2));
+    let now = Instant::now();
+    let day = today();
+    let acquire = |family, now| limiter.acquire_at(family, now, day).unwrap();
+
+    assert_eq!(acquire(EndpointFamily::Geo, now), Duration::ZERO);
+    assert_eq!(acquire(EndpointFamily::Geo, now), Duration::ZERO);
+    assert_eq!(
+        acquire(EndpointFamily::Geo, now),
+        Duration::from_millis(500)
+    );
+    assert_eq!(
+        acquire(EndpointFamily::Geo, now),
+        Duration::from_millis(1000)
+    );
+    // 其他类别不受限制
+    assert_eq!(acquire(EndpointFamily::Weather, now), Duration::ZERO);
+    // 令牌恢复
+    let later = now + Duration::from_secs(3);
+    assert_eq!(acquire(EndpointFamily::Geo, later), Duration::ZERO);
+
+    let limiter = RateLimiter::new().with_daily_budget(2);
+    assert_eq!(limiter.remaining(), Some(2));
+    limiter
+        .acquire_at(EndpointFamily::Weather, now, day)
+        .unwrap();
+    limiter.acquire_at(EndpointFamily::Air, now, day).unwrap();
+    assert_eq!(limiter.remaining(), Some(0));
+    assert!(matches!(
+        limiter.acquire_at(EndpointFamily::Weather, now, day),
+        Err(Error::QuotaExhausted { limit: 2 })
+    ));
+    // 次日重置
+    let tomorrow = day.succ_opt().unwrap();
+    limiter
+        .acquire_at(EndpointFamily::Weather, now, tomorrow)
+        .unwrap();
+    assert_eq!(limiter.lock().budget.as_ref().unwrap().used, 1);
+
+    // 保存并恢复额度
+    let saved = serde_json::to_string(&QuotaBudget {
+        limit: 10,
+        used: 4,
+        date: today(),
+    })
+    .unwrap();
+    let limiter = RateLimiter::new().with_budget(serde_json::from_str(&saved).unwrap());
+    assert_eq!(limiter.remaining(), Some(6));
+    limiter.exhaust();
+    assert_eq!(limiter.remaining(), Some(0));
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_client_quota_budget() {
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        transport::MockTransport,
+    };
+
+    let transport = MockTransport::with_handler(|_| {
+        Ok(crate::transport::Response::json(
+            &serde_json::json!({ "code": "200" }),
+        ))
+    });
+    let limiter = RateLimiter::new().with_daily_budget(2);
+    let mut config = ClientConfig::new("id", "key");
+    config.rate_limiter = Some(limiter.clone());
+    let client = QWeatherClient::with_transport(config, transport.clone());
+
+    let url = "https://devapi.qweather.com/v7/weather/now".to_string();
+    for _ in 0..2 {
+        let resp: APIResponse<serde_json::Value> = client
+            .request_api(url.clone(), Default::default())
+            .await
+            .unwrap();
+        assert!(matches!(resp, APIResponse::Success(_)));
+    }
+    let result = client
+        .request_api::<serde_json::Value>(url, Default::default())
+        .await;
+    assert!(matches!(result, Err(Error::QuotaExhausted { limit: 2 })));
+    assert_eq!(transport.requests().len(), 2);
+    assert_eq!(limiter.remaining(), Some(0));
+
+    // 服务端返回402时同样标记额度用完
+    let transport = MockTransport::new();
+    transport.push_json(serde_json::json!({ "code": "402" }));
+    let limiter = RateLimiter::new().with_daily_budget(100);
+    let mut config = ClientConfig::new("id", "key");
+    config.rate_limiter = Some(limiter.clone());
+    let client = QWeatherClient::with_transport(config, transport);
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(
+            "https://devapi.qweather.com/v7/warning/now".to_string(),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+    assert!(matches!(resp, APIResponse::Error(code) if code == "402"));
+    assert_eq!(limiter.remaining(), Some(0));
+}
@@ -0,0 +1,246 @@
+//! 请求重试策略
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::{Duration, SystemTime},
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::transport::Response;
+
+/// 重试策略
+///
+/// 默认只请求一次，不重试。每次重试都会使用新的时间戳 `t` 重新签名。
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// 最多请求次数，包括第一次请求
+    pub max_attempts: u32,
+    /// 第一次重试前的等待时间，之后每次翻倍
+    pub base_delay: Duration,
+    /// 最长等待时间。响应头 `Retry-After` 要求的等待时间超过该值时不再重试，直接返回该响应
+    pub max_delay: Duration,
+    /// 是否在 0 到计算出的等待时间之间随机取值，避免大量客户端同时重试
+    pub jitter: bool,
+    /// 是否重试超时、连接失败等传输错误
+    pub retry_transport_errors: bool,
+    /// 需要重试的HTTP状态码
+    pub retry_http_status: Vec<u16>,
+    /// 需要重试的[状态码](https://dev.qweather.com/docs/resource/status-code/)
+    pub retry_codes: Vec<String>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retry_transport_errors: true,
+            retry_http_status: vec![429, 500, 502, 503, 504],
+            retry_codes: vec!["429".to_string(), "500".to_string()],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 不重试
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// 最多请求 `max_attempts` 次，其他设置使用默认值
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts,
+            ..Default::default()
+        }
+    }
+
+    /// 响应是否需要重试
+    pub(crate) fn should_retry_response(&self, status: u16, code: Option<&str>) -> bool {
+        self.retry_http_status.contains(&status)
+            || code.is_some_and(|code| self.retry_codes.iter().any(|retry| retry == code))
+    }
+
+    /// 第 `attempt` 次请求失败后的等待时间，`Retry-After` 超过 `max_delay` 时返回 `None`，不再重试
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if let Some(retry_after) = retry_after {
+            return (retry_after <= self.max_delay).then_some(retry_after);
+        }
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            Some(delay.mul_f64(random_fraction()))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+/// 解析 `Retry-After` 响应头，支持秒数和HTTP日期两种格式
+pub(crate) fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.header("retry-after")?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+
+    (date.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+/// [0, 1) 之间的随机数
+pub(crate) fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    if let Ok(now) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        hasher.write_u128(now.as_nanos());
+    }
+
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[test]
+fn test_retry_delay() {
+    let policy = RetryPolicy {
+        max_attempts: 5,
+        base_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(500),
+        jitter: false,
+        ..Default::default()
+    };
+    assert_eq!(policy.delay(1, None), Some(Duration::from_millis(100)));
+    assert_eq!(policy.delay(2, None), Some(Duration::from_millis(200)));
+    assert_eq!(policy.delay(3, None), Some(Duration::from_millis(400)));
+    assert_eq!(policy.delay(4, None), Some(Duration::from_millis(500)));
+    assert_eq!(policy.delay(40, None), Some(Duration::from_millis(500)));
+    assert_eq!(
+        policy.delay(1, Some(Duration::from_millis(300))),
+        Some(Duration::from_millis(300))
+    );
+    // 不提前重试，超过最长等待时间时放弃重试
+    assert_eq!(policy.delay(1, Some(Duration::from_secs(60))), None);
+
+    let jittered = RetryPolicy {
+        jitter: true,
+        ..policy.clone()
+    };
+    for attempt in 1..5 {
+        assert!(jittered.delay(attempt, None).unwrap() <= Duration::from_millis(500));
+    }
+
+    assert!(policy.should_retry_response(503, None));
+    assert!(policy.should_retry_response(200, Some("429")));
+    assert!(!policy.should_retry_response(200, Some("401")));
+
+    let mut response = Response::json(&serde_json::json!({}));
+    assert_eq!(retry_after(&response), None);
+    response
+        .headers
+        .insert("retry-after".to_string(), "3".to_string());
+    assert_eq!(retry_after(&response), Some(Duration::from_secs(3)));
+    let date = (Utc::now() + chrono::Duration::seconds(30)).to_rfc2822();
+    response.headers.insert("retry-after".to_string(), date);
+    let delay = retry_after(&response).unwrap();
+    assert!(delay > Duration::from_secs(25) && delay <= Duration::from_secs(30));
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_retry_with_mock_transport() {
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+
+    use crate::{
+        api::APIResponse,
+        client::{ClientConfig, QWeatherClient},
+        transport::{MockTransport, Response},
+    };
+
+    let retry_after = |seconds: &str| {
+        let mut response = Response::json(&serde_json::json!({ "code": "429" }));
+        response
+            .headers
+            .insert("retry-after".to_string(), seconds.to_string());
+        response
+    };
+    let calls = Arc::new(AtomicUsize::new(0));
+    let transport = {
+        let calls = calls.clone();
+        MockTransport::with_handler(move |_| {
+            Ok(match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Response {
+                    status: 503,
+                    headers: BTreeMap::new(),
+                    body: Vec::new(),
+                },
+                1 => retry_after("0"),
+                _ => Response::json(&serde_json::json!({ "code": "200", "value": 1 })),
+            })
+        })
+    };
+
+    let mut config = ClientConfig::new("id", "key");
+    config.retry = RetryPolicy {
+        max_attempts: 3,
+        base_delay: Duration::from_millis(1),
+        ..Default::default()
+    };
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let url = "https://devapi.qweather.com/v7/weather/now".to_string();
+
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.clone(), Default::default())
+        .await
+        .unwrap();
+    match resp {
+        APIResponse::Success(value) => assert_eq!(value["value"], 1),
+        other => panic!("unexpected response {:?}", other),
+    }
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    for request in &requests {
+        assert_eq!(request.url, url);
+        assert!(request.params.contains_key("t"));
+        assert!(request.params.contains_key("sign"));
+    }
+
+    // 超过最多请求次数后返回最后一次的错误
+    calls.store(0, Ordering::SeqCst);
+    let mut config = ClientConfig::new("id", "key");
+    config.retry = RetryPolicy {
+        max_attempts: 2,
+        base_delay: Duration::from_millis(1),
+        ..Default::default()
+    };
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let resp: APIResponse<serde_json::Value> = client
+        .request_api(url.clone(), Default::default())
+        .await
+        .unwrap();
+    match resp {
+        APIResponse::Error(code) => assert_eq!(code, "429"),
+        other => panic!("expected error, got {:?}", other),
+    }
+    assert_eq!(transport.requests().len(), 5);
+
+    // `Retry-After` 超过最长等待时间时不提前重试，直接返回该响应
+    let transport = MockTransport::with_handler(move |_| Ok(retry_after("60")));
+    let mut config = ClientConfig::new("id", "key");
+    config.retry = RetryPolicy::new(3);
+    let client = QWeatherClient::with_transport(config, transport.clone());
+    let resp: APIResponse<serde_json::Value> =
+        client.request_api(url, Default::default()).await.unwrap();
+    assert!(matches!(resp, APIResponse::Error(code) if code == "429"));
+    assert_eq!(transport.requests().len(), 1);
+}
@@ -0,0 +1,184 @@
+//! 请求签名
+//!
+//! 按[签名认证](https://dev.qweather.com/docs/resource/signature-auth/)规则签名：参数按名称排序，
+//! 跳过 `sign`、`key`（不区分大小写）和值为空的参数，以 `名称=值` 用 `&` 连接后拼接私钥，取MD5的16进制小写值。
+//!
+//! 服务端可以用 [`Signer`] 为无法保存私钥的客户端生成带有时间戳的签名URL：
+//!
+//! ```
+//! use std::collections::BTreeMap;
+//!
+//! use qweather_sdk::signer::Signer;
+//!
+//! let signer = Signer::new("HE1234", "secret");
+//! let mut params = BTreeMap::new();
+//! params.insert("location".to_string(), "101010100".to_string());
+//! let url = signer
+//!     .signed_url("https://devapi.qweather.com/v7/weather/now", params, 1700000000)
+//!     .unwrap();
+//! assert_eq!(
+//!     url,
+//!     "https://devapi.qweather.com/v7/weather/now?location=101010100&publicid=HE1234&t=1700000000&sign=e2c0a5d1fccedc927f02ebf4852b8c44"
+//! );
+//! ```
+
+use std::{collections::BTreeMap, fmt};
+
+use md5::{Digest, Md5};
+use url::Url;
+
+/// 请求签名器
+#[derive(Clone)]
+pub struct Signer {
+    public_id: String,
+    private_key: String,
+}
+
+impl fmt::Debug for Signer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signer")
+            .field("public_id", &self.public_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Signer {
+    /// 使用公钥和私钥创建签名器
+    pub fn new(public_id: impl ToString, private_key: impl ToString) -> Self {
+        Signer {
+            public_id: public_id.to_string(),
+            private_key: private_key.to_string(),
+        }
+    }
+
+    /// 公钥
+    pub fn public_id(&self) -> &str {
+        &self.public_id
+    }
+
+    /// 计算参数的签名，参数中需要已包含 `publicid` 和 `t`
+    pub fn sign(&self, params: &BTreeMap<String, String>) -> String {
+        let mut sign = String::new();
+
+        for (key, value) in params {
+            if key.to_lowercase() == "sign" || key.to_lowercase() == "key" {
+                continue;
+            }
+            if value.is_empty() {
+                continue;
+            }
+            sign.push_str(&format!("{}={}&", key, value));
+        }
+        sign.pop();
+        sign.push_str(&self.private_key);
+        let mut hasher = Md5::new();
+        hasher.update(&sign);
+
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// 添加 `publicid`、时间戳 `t`（秒）和签名 `sign`
+    pub fn signed_params(
+        &self,
+        mut params: BTreeMap<String, String>,
+        timestamp: i64,
+    ) -> BTreeMap<String, String> {
+        params.remove("sign");
+        params.insert("publicid".to_string(), self.public_id.clone());
+        params.insert("t".to_string(), timestamp.to_string());
+        let sign = self.sign(&params);
+        params.insert("sign".to_string(), sign);
+
+        params
+    }
+
+    /// 签名后的查询字符串，`sign` 在最后
+    pub fn signed_query(&self, params: BTreeMap<String, String>, timestamp: i64) -> String {
+        let mut params = self.signed_params(params, timestamp);
+        let sign = params.remove("sign").unwrap_or_default();
+        let mut query = Url::parse("http://localhost/").expect("valid url");
+        query
+            .query_pairs_mut()
+            .extend_pairs(&params)
+            .append_pair("sign", &sign);
+
+        query.query().unwrap_or_default().to_string()
+    }
+
+    /// 签名后的完整URL，`url` 为接口地址，例如 `https://devapi.qweather.com/v7/weather/now`
+    pub fn signed_url(
+        &self,
+        url: &str,
+        params: BTreeMap<String, String>,
+        timestamp: i64,
+    ) -> Result<String, url::ParseError> {
+        let mut url = Url::parse(url)?;
+        url.set_query(Some(&self.signed_query(params, timestamp)));
+
+        Ok(url.to_string())
+    }
+}
+
+#[test]
+fn test_signer_known_answers() {
+    let signer = Signer::new("HE1234", "secret");
+    let params = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    };
+
+    let base = [
+        ("location", "101010100"),
+        ("publicid", "HE1234"),
+        ("t", "1700000000"),
+    ];
+    // md5("location=101010100&publicid=HE1234&t=1700000000secret")
+    assert_eq!(
+        signer.sign(&params(&base)),
+        "e2c0a5d1fccedc927f02ebf4852b8c44"
+    );
+    // 跳过空值以及 sign、key 参数，不区分大小写
+    let mut skipped = base.to_vec();
+    skipped.extend([("lang", ""), ("sign", "x"), ("KEY", "y"), ("key", "z")]);
+    assert_eq!(
+        signer.sign(&params(&skipped)),
+        "e2c0a5d1fccedc927f02ebf4852b8c44"
+    );
+    // md5("lang=zh&location=116.41,39.92&publicid=HE1234&t=1700000000&unit=msecret")
+    assert_eq!(
+        signer.sign(&params(&[
+            ("location", "116.41,39.92"),
+            ("publicid", "HE1234"),
+            ("t", "1700000000"),
+            ("lang", "zh"),
+            ("unit", "m"),
+        ])),
+        "ad1e49c54a0015a90e827ac4c4ea629a"
+    );
+    // md5("secret")
+    assert_eq!(
+        signer.sign(&BTreeMap::new()),
+        "5ebe2294ecd0e0f08eab7690d2a6ee69"
+    );
+
+    let signed = signer.signed_params(
+        params(&[("location", "101010100"), ("sign", "old")]),
+        1700000000,
+    );
+    assert_eq!(signed, {
+        let mut expected = params(&base);
+        expected.insert(
+            "sign".to_string(),
+            "e2c0a5d1fccedc927f02ebf4852b8c44".to_string(),
+        );
+        expected
+    });
+    assert_eq!(
+        signer.signed_query(params(&[("location", "116.41,39.92")]), 1700000000),
+        "location=116.41%2C39.92&publicid=HE1234&t=1700000000&sign=f1bd10bb35576b17b561117c92c81fe0"
+    );
+    assert!(format!("{:?}", signer).contains("HE1234"));
+    assert!(!format!("{:?}", signer).contains("secret"));
+}
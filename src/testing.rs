@@ -0,0 +1,488 @@
+//! 本地模拟的和风天气服务
+//!
+//! [`FakeServer`] 在本机随机端口上提供SDK支持的所有接口，默认返回 [`fixtures`] 中的 `success` 样例，
+//! 也可以为每个接口指定其他样例、错误状态码或自定义处理函数。和真实服务一样校验 `publicid`、
+//! 时间戳 `t` 和签名 `sign`，并可以模拟网络延迟和请求额度用完。需要开启 `testing` feature。
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use qweather_sdk::{api::APIResponse, testing::FakeServer};
+//!
+//! # async fn run() {
+//! let server = FakeServer::start().await.unwrap();
+//! server.serve_fixture("weather_now", "imperial");
+//! server.fail_with("weather_daily_forecast", "429");
+//! server.set_latency(Duration::from_millis(200));
+//! server.set_quota(Some(100));
+//!
+//! let client = server.client();
+//! let resp = client.weather_now("101010100").await.unwrap();
+//! assert!(matches!(resp, APIResponse::Success(_)));
+//! assert_eq!(server.requests()[0].endpoint, Some("weather_now"));
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, io,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{StatusCode, Uri},
+    response::IntoResponse,
+    Router,
+};
+use tokio::{net::TcpListener, sync::oneshot, time::sleep};
+
+use crate::{
+    client::{ClientConfig, QWeatherClient},
+    fixtures,
+    signer::Signer,
+    transport::Response,
+};
+
+/// 默认公钥
+pub const PUBLIC_ID: &str = "fake-public-id";
+/// 默认私钥
+pub const PRIVATE_KEY: &str = "fake-private-key";
+
+/// 请求地址的路径对应的接口名称，与 [`fixtures`] 中的接口名称相同
+///
+/// 例如 `/v7/weather/3d` 为 `weather_daily_forecast`，`/airquality/v1/current/39.90/116.40` 为 `air_current`
+pub fn endpoint(path: &str) -> Option<&'static str> {
+    let segments: Vec<_> = path.trim_matches('/').split('/').collect();
+    // 预报天数或小时数，例如 `3d`、`24h`
+    let period = |segment: &str, unit: char| {
+        segment
+            .strip_suffix(unit)
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+    };
+    let endpoint = match segments.as_slice() {
+        ["v7", "weather", "now"] => "weather_now",
+        ["v7", "weather", n] if period(n, 'd') => "weather_daily_forecast",
+        ["v7", "weather", n] if period(n, 'h') => "weather_hourly_forecast",
+        ["v7", "grid-weather", "now"] => "grid_weather_now",
+        ["v7", "grid-weather", n] if period(n, 'd') => "grid_weather_daily_forecast",
+        ["v7", "grid-weather", n] if period(n, 'h') => "grid_weather_hourly_forecast",
+        ["v7", "minutely", "5m"] => "minutely_precipitation",
+        ["v7", "warning", "now"] => "weather_warning",
+        ["v7", "warning", "list"] => "weather_warning_city_list",
+        ["v7", "indices", n] if period(n, 'd') => "indices_forecast",
+        ["v7", "tropical", "storm-forecast"] => "storm_forecast",
+        ["airquality", "v1", "current", _, _] => "air_current",
+        ["airquality", "v1", "hourly", _, _] => "air_hourly_forecast",
+        ["airquality", "v1", "daily", _, _] => "air_daily_forecast",
+        ["airquality", "v1", "station", _] => "air_station",
+        ["v2", "city", "lookup"] => "geo_city_lookup",
+        ["v2", "city", "top"] => "geo_city_top",
+        ["v2", "poi", "lookup"] => "geo_poi_lookup",
+        ["v2", "poi", "range"] => "geo_poi_range",
+        _ => return None,
+    };
+
+    Some(endpoint)
+}
+
+/// 收到的请求
+#[derive(Debug, Clone, PartialEq)]
+pub struct FakeRequest {
+    /// 请求路径，例如 `/v7/weather/now`
+    pub path: String,
+    /// 接口名称，未知接口为 `None`
+    pub endpoint: Option<&'static str>,
+    /// 查询参数，包含 `publicid`、`t` 和 `sign`
+    pub params: BTreeMap<String, String>,
+}
+
+type Handler = Arc<dyn Fn(&FakeRequest) -> Response + Send + Sync>;
+
+/// 接口的响应方式
+#[derive(Clone)]
+enum Route {
+    /// 返回响应样例
+    Fixture(&'static fixtures::Fixture),
+    /// 返回错误状态码
+    Code(String),
+    /// 调用处理函数
+    Handler(Handler),
+}
+
+struct ServerState {
+    signer: Signer,
+    routes: HashMap<&'static str, Route>,
+    latency: Duration,
+    quota: Option<u64>,
+    timestamp_window: Option<Duration>,
+    requests: Vec<FakeRequest>,
+}
+
+/// 本地模拟的和风天气服务，销毁时停止
+pub struct FakeServer {
+    addr: SocketAddr,
+    public_id: String,
+    private_key: String,
+    state: Arc<Mutex<ServerState>>,
+    shutdown: Option<oneshot::Sender<()>>,
+}
+
+impl fmt::Debug for FakeServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FakeServer")
+            .field("addr", &self.addr)
+            .field("public_id", &self.public_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FakeServer {
+    /// 使用默认的 [`PUBLIC_ID`] 和 [`PRIVATE_KEY`] 启动服务，需要在 tokio 运行时中调用
+    pub async fn start() -> io::Result<Self> {
+        Self::start_with_credentials(PUBLIC_ID, PRIVATE_KEY).await
+    }
+
+    /// 使用指定的公钥和私钥启动服务，需要在 tokio 运行时中调用
+    pub async fn start_with_credentials(
+        public_id: impl ToString,
+        private_key: impl ToString,
+    ) -> io::Result<Self> {
+        let public_id = public_id.to_string();
+        let private_key = private_key.to_string();
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(ServerState {
+            signer: Signer::new(&public_id, &private_key),
+            routes: HashMap::new(),
+            latency: Duration::ZERO,
+            quota: None,
+            timestamp_window: Some(Duration::from_secs(300)),
+            requests: Vec::new(),
+        }));
+
+        let router = Router::new().fallback(handle).with_state(state.clone());
+        let (shutdown, stopped) = oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, router)
+                .with_graceful_shutdown(async {
+                    let _ = stopped.await;
+                })
+                .await;
+        });
+
+        Ok(FakeServer {
+            addr,
+            public_id,
+            private_key,
+            state,
+            shutdown: Some(shutdown),
+        })
+    }
+
+    /// 服务地址，例如 `http://127.0.0.1:12345`
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// 指向本服务的客户端配置
+    pub fn config(&self) -> ClientConfig {
+        let mut config = ClientConfig::new(&self.public_id, &self.private_key);
+        config.api_host = Some(self.url());
+        config.geo_api_host = Some(self.url());
+        config
+    }
+
+    /// 指向本服务的客户端
+    pub fn client(&self) -> QWeatherClient {
+        QWeatherClient::with_config(self.config())
+    }
+
+    /// 接口返回指定的响应样例，样例不存在时 panic
+    pub fn serve_fixture(&self, endpoint: &str, name: &str) {
+        let Some(fixture) = fixtures::get(endpoint, name) else {
+            panic!("no fixture {}/{}", endpoint, name);
+        };
+        self.route(fixture.endpoint, Route::Fixture(fixture));
+    }
+
+    /// 接口返回[状态码](https://dev.qweather.com/docs/resource/status-code/)，例如 `404`、`429`
+    ///
+    /// `4xx`、`5xx` 的HTTP状态码与 `code` 相同，其他为200
+    pub fn fail_with(&self, endpoint: &str, code: impl ToString) {
+        self.route(known(endpoint), Route::Code(code.to_string()));
+    }
+
+    /// 使用处理函数生成接口的响应
+    pub fn respond_with(
+        &self,
+        endpoint: &str,
+        handler: impl Fn(&FakeRequest) -> Response + Send + Sync + 'static,
+    ) {
+        self.route(known(endpoint), Route::Handler(Arc::new(handler)));
+    }
+
+    /// 恢复接口的默认响应，即 `success` 样例
+    pub fn reset(&self, endpoint: &str) {
+        self.state.lock().unwrap().routes.remove(endpoint);
+    }
+
+    /// 每个请求的延迟
+    pub fn set_latency(&self, latency: Duration) {
+        self.state.lock().unwrap().latency = latency;
+    }
+
+    /// 剩余的请求额度，用完后返回 `402`，`None` 为不限
+    pub fn set_quota(&self, quota: Option<u64>) {
+        self.state.lock().unwrap().quota = quota;
+    }
+
+    /// 剩余的请求额度
+    pub fn remaining_quota(&self) -> Option<u64> {
+        self.state.lock().unwrap().quota
+    }
+
+    /// 时间戳 `t` 与服务器时间允许的最大偏差，默认5分钟，`None` 为不校验时间戳
+    pub fn set_timestamp_window(&self, window: Option<Duration>) {
+        self.state.lock().unwrap().timestamp_window = window;
+    }
+
+    /// 收到的所有请求
+    pub fn requests(&self) -> Vec<FakeRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    fn route(&self, endpoint: &'static str, route: Route) {
+        self.state.lock().unwrap().routes.insert(endpoint, route);
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+/// 检查接口名称，返回静态的名称
+fn known(endpoint: &str) -> &'static str {
+    match fixtures::endpoint(endpoint).next() {
+        Some(fixture) => fixture.endpoint,
+        None => panic!("unknown endpoint {}", endpoint),
+    }
+}
+
+/// 只有 `code` 的响应
+fn code_response(code: &str) -> Response {
+    let mut response = Response::json(&serde_json::json!({ "code": code }));
+    if let Ok(status) = code.parse::<u16>() {
+        if status >= 400 {
+            response.status = status;
+        }
+    }
+    response
+}
+
+/// 校验 `publicid`、时间戳和签名
+fn verify(state: &ServerState, params: &BTreeMap<String, String>) -> bool {
+    if params.get("publicid").map(String::as_str) != Some(state.signer.public_id()) {
+        return false;
+    }
+    if let Some(window) = state.timestamp_window {
+        let Some(t) = params.get("t").and_then(|t| t.parse::<i64>().ok()) else {
+            return false;
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        if now.abs_diff(t) > window.as_secs() {
+            return false;
+        }
+    }
+    params.get("sign") == Some(&state.signer.sign(params))
+}
+
+async fn handle(
+    State(state): State<Arc<Mutex<ServerState>>>,
+    uri: Uri,
+) -> axum::response::Response {
+    let params = url::form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+        .into_owned()
+        .collect();
+    let request = FakeRequest {
+        path: uri.path().to_string(),
+        endpoint: endpoint(uri.path()),
+        params,
+    };
+
+    let (latency, route) = {
+        let mut state = state.lock().unwrap();
+        state.requests.push(request.clone());
+        let route = if !verify(&state, &request.params) {
+            Err("401")
+        } else if state.quota == Some(0) {
+            Err("402")
+        } else {
+            if let Some(quota) = state.quota.as_mut() {
+                *quota -= 1;
+            }
+            match request.endpoint {
+                Some(endpoint) => Ok(state.routes.get(endpoint).cloned()),
+                None => Err("404"),
+            }
+        };
+        (state.latency, route)
+    };
+    if !latency.is_zero() {
+        sleep(latency).await;
+    }
+
+    let response = match route {
+        Err(code) => code_response(code),
+        Ok(Some(Route::Fixture(fixture))) => fixture.response(),
+        Ok(Some(Route::Code(code))) => code_response(&code),
+        Ok(Some(Route::Handler(handler))) => handler(&request),
+        Ok(None) => match request
+            .endpoint
+            .and_then(|endpoint| fixtures::get(endpoint, "success"))
+        {
+            Some(fixture) => fixture.response(),
+            None => code_response("204"),
+        },
+    };
+
+    let mut builder = axum::response::Response::builder()
+        .status(StatusCode::from_u16(response.status).unwrap_or(StatusCode::OK));
+    for (name, value) in &response.headers {
+        builder = builder.header(name, value);
+    }
+    builder
+        .body(Body::from(response.body))
+        .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())
+}
+
+#[cfg(test)]
+#[tokio::test]
+async fn test_fake_server() {
+    use crate::api::APIResponse;
+
+    let server = FakeServer::start().await.unwrap();
+    let client = server.client();
+
+    // 默认返回 `success` 样例
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Success(resp) => assert_eq!(resp.now.text, "多云"),
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(matches!(
+        client.air_current(39.90, 116.40).await.unwrap(),
+        APIResponse::Success(_)
+    ));
+    assert!(matches!(
+        client
+            .geo_poi_range(crate::api::geo::GeoPoiRangeInput {
+                location: "116.40,39.90",
+                type_: "scenic",
+                radius: None,
+                number: None,
+            })
+            .await
+            .unwrap(),
+        APIResponse::Success(_)
+    ));
+    let request = &server.requests()[0];
+    assert_eq!(request.path, "/v7/weather/now");
+    assert_eq!(request.endpoint, Some("weather_now"));
+    assert_eq!(request.params["location"], "101010100");
+    assert_eq!(request.params["publicid"], PUBLIC_ID);
+
+    server.serve_fixture("weather_now", "imperial");
+    match client.weather_now("101010100").await.unwrap() {
+        APIResponse::Success(resp) => assert_eq!(resp.now.temp, 75.0),
+        other => panic!("unexpected {:?}", other),
+    }
+
+    server.fail_with("weather_daily_forecast", "404");
+    assert!(matches!(
+        client.weather_daily_forecast("101010100", 3).await.unwrap(),
+        APIResponse::Error(code) if code == "404"
+    ));
+
+    server.respond_with("weather_hourly_forecast", |request| {
+        assert_eq!(request.path, "/v7/weather/24h");
+        code_response("204")
+    });
+    assert!(matches!(
+        client.weather_hourly_forecast("101010100", 24).await.unwrap(),
+        APIResponse::Error(code) if code == "204"
+    ));
+    server.reset("weather_hourly_forecast");
+    assert!(matches!(
+        client
+            .weather_hourly_forecast("101010100", 24)
+            .await
+            .unwrap(),
+        APIResponse::Success(_)
+    ));
+
+    // 签名错误
+    let wrong_key = QWeatherClient::with_config({
+        let mut config = server.config();
+        config.private_key = "wrong".to_string();
+        config
+    });
+    assert!(matches!(
+        wrong_key.weather_now("101010100").await.unwrap(),
+        APIResponse::Error(code) if code == "401"
+    ));
+
+    // 额度用完
+    server.set_quota(Some(1));
+    assert!(matches!(
+        client.weather_now("101010100").await.unwrap(),
+        APIResponse::Success(_)
+    ));
+    assert_eq!(server.remaining_quota(), Some(0));
+    assert!(matches!(
+        client.weather_now("101010100").await.unwrap(),
+        APIResponse::Error(code) if code == "402"
+    ));
+    server.set_quota(None);
+
+    let start = std::time::Instant::now();
+    server.set_latency(Duration::from_millis(100));
+    client.weather_now("101010100").await.unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[test]
+fn test_endpoint() {
+    assert_eq!(endpoint("/v7/weather/now"), Some("weather_now"));
+    assert_eq!(endpoint("/v7/weather/30d"), Some("weather_daily_forecast"));
+    assert_eq!(
+        endpoint("/v7/grid-weather/72h"),
+        Some("grid_weather_hourly_forecast")
+    );
+    assert_eq!(endpoint("/v7/indices/1d"), Some("indices_forecast"));
+    assert_eq!(
+        endpoint("/airquality/v1/current/39.90/116.40"),
+        Some("air_current")
+    );
+    assert_eq!(
+        endpoint("/airquality/v1/station/P58911"),
+        Some("air_station")
+    );
+    assert_eq!(endpoint("/v2/poi/range"), Some("geo_poi_range"));
+    assert_eq!(endpoint("/v7/weather/d"), None);
+    assert_eq!(endpoint("/v7/unknown"), None);
+
+    // 每个接口都有默认的响应样例
+    for fixture in fixtures::FIXTURES {
+        assert!(fixtures::get(fixture.endpoint, "success").is_some());
+    }
+}
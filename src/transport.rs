@@ -0,0 +1,225 @@
+//! HTTP传输层
+//!
+//! [`QWeatherClient`](crate::client::QWeatherClient) 通过 [`Transport`] 发送请求，默认使用
+//! [`ReqwestTransport`]。测试时可以使用 [`MockTransport`] 在不访问网络的情况下驱动客户端。
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::Client;
+
+use crate::Error;
+
+/// 可以跨线程传递的异步结果
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// HTTP请求
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// 请求地址，不含查询参数
+    pub url: String,
+    /// 查询参数
+    pub params: BTreeMap<String, String>,
+    /// 请求头
+    pub headers: BTreeMap<String, String>,
+}
+
+impl Request {
+    /// 创建GET请求
+    pub fn get(url: impl ToString, params: BTreeMap<String, String>) -> Self {
+        Request {
+            url: url.to_string(),
+            params,
+            headers: BTreeMap::new(),
+        }
+    }
+}
+
+/// HTTP响应
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// 状态码
+    pub status: u16,
+    /// 响应头，名称均为小写
+    pub headers: BTreeMap<String, String>,
+    /// 响应体
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// 创建状态码为200的JSON响应
+    pub fn json(body: &serde_json::Value) -> Self {
+        let mut headers = BTreeMap::new();
+        headers.insert("content-type".to_string(), "application/json".to_string());
+
+        Response {
+            status: 200,
+            headers,
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    /// 获取响应头，名称不区分大小写
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+}
+
+/// 请求地址中的路径部分，例如 `/v7/weather/now`
+pub(crate) fn url_path(url: &str) -> &str {
+    match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("", |i| &rest[i..]),
+        None => url,
+    }
+}
+
+/// HTTP传输
+pub trait Transport: Send + Sync {
+    /// 发送请求
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>>;
+}
+
+/// 基于 reqwest 的默认传输
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// 使用已配置好的 reqwest 客户端，例如设置了代理、根证书或超时
+    pub fn new(client: Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        Box::pin(async move {
+            let mut builder = self.client.get(&request.url).query(&request.params);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            // reqwest 错误中的URL包含 `publicid` 和 `sign`，不能出现在日志和指标中
+            let response = builder.send().await.map_err(reqwest::Error::without_url)?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (name.as_str().to_ascii_lowercase(), value.to_string()))
+                })
+                .collect();
+            let body = response
+                .bytes()
+                .await
+                .map_err(reqwest::Error::without_url)?
+                .to_vec();
+
+            Ok(Response {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+type Handler = dyn Fn(&Request) -> Result<Response, Error> + Send + Sync;
+
+#[derive(Default)]
+struct MockState {
+    responses: VecDeque<Result<Response, Error>>,
+    requests: Vec<Request>,
+}
+
+/// 内存中的模拟传输
+///
+/// 优先按顺序返回预先放入的响应，队列为空时调用处理函数，两者都没有时返回传输错误。
+/// 所有收到的请求都会被记录下来。
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+    handler: Option<Arc<Handler>>,
+}
+
+impl MockTransport {
+    /// 创建新的模拟传输
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 使用处理函数生成响应
+    pub fn with_handler(
+        handler: impl Fn(&Request) -> Result<Response, Error> + Send + Sync + 'static,
+    ) -> Self {
+        MockTransport {
+            state: Default::default(),
+            handler: Some(Arc::new(handler)),
+        }
+    }
+
+    /// 放入一个响应
+    pub fn push_response(&self, response: Response) {
+        self.state.lock().unwrap().responses.push_back(Ok(response));
+    }
+
+    /// 放入一个状态码为200的JSON响应
+    pub fn push_json(&self, body: serde_json::Value) {
+        self.push_response(Response::json(&body));
+    }
+
+    /// 放入一个传输错误
+    pub fn push_error(&self, error: impl ToString) {
+        self.state
+            .lock()
+            .unwrap()
+            .responses
+            .push_back(Err(Error::Transport(error.to_string())));
+    }
+
+    /// 已收到的请求
+    pub fn requests(&self) -> Vec<Request> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("MockTransport")
+            .field("pending", &state.responses.len())
+            .field("requests", &state.requests.len())
+            .field("handler", &self.handler.is_some())
+            .finish()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, Error>> {
+        let result = {
+            let mut state = self.state.lock().unwrap();
+            state.requests.push(request.clone());
+            state.responses.pop_front()
+        };
+        let result = match (result, &self.handler) {
+            (Some(result), _) => result,
+            (None, Some(handler)) => handler(&request),
+            (None, None) => Err(Error::Transport(format!(
+                "no mock response for {}",
+                request.url
+            ))),
+        };
+
+        Box::pin(async move { result })
+    }
+}
@@ -0,0 +1,323 @@
+//! 轮询订阅
+//!
+//! 按固定间隔轮询接口，数据更新时才返回。第一次请求前等待最多间隔 10% 的随机时间，之后每次等待
+//! 也会额外加上最多间隔 10% 的随机时间，避免同时创建的大量订阅同时请求。流被丢弃时轮询随之停止，不会留下后台任务。
+//!
+//! ```no_run
+//! use std::time::Duration;
+//!
+//! use futures_util::StreamExt;
+//! use qweather_sdk::client::{ClientConfig, QWeatherClient};
+//!
+//! # async fn run() {
+//! let client = QWeatherClient::with_config(ClientConfig::new("id", "key"));
+//! let mut updates = Box::pin(client.watch_weather_now("101010100", Duration::from_secs(300)));
+//! while let Some(update) = updates.next().await {
+//!     println!("{:?}", update);
+//! }
+//! # }
+//! ```
+
+use std::{collections::HashMap, future::Future, time::Duration};
+
+use chrono::{DateTime, FixedOffset};
+use futures_util::{stream, Stream};
+use tokio::time::sleep;
+
+use crate::{
+    api::{
+        air_quality::AirCurrentResponse, minutely::MinutePrecipitationResponse,
+        warning::WeatherWarningResponse, weather::WeatherNowResponse, APIResponse,
+    },
+    client::QWeatherClient,
+    retry::random_fraction,
+    APIResult,
+};
+
+/// 间隔加上最多 10% 的随机时间
+fn jittered(interval: Duration) -> Duration {
+    interval + interval.mul_f64(0.1 * random_fraction())
+}
+
+impl QWeatherClient {
+    /// 轮询接口，由 `diff` 决定每次成功的响应是否返回
+    ///
+    /// 第一次请求前等待 0 到 `interval` 的 10%，之后每隔 `interval` 请求一次。错误总是返回，之后继续轮询。
+    pub fn poll<'a, T, S, F, Fut, D>(
+        &'a self,
+        interval: Duration,
+        state: S,
+        request: F,
+        diff: D,
+    ) -> impl Stream<Item = APIResult<T>> + 'a
+    where
+        T: 'a,
+        S: 'a,
+        F: Fn(&'a QWeatherClient) -> Fut + 'a,
+        Fut: Future<Output = APIResult<T>> + 'a,
+        D: FnMut(&mut S, T) -> Option<T> + 'a,
+    {
+        stream::unfold(
+            (true, state, request, diff),
+            move |(first, mut state, request, mut diff)| async move {
+                if first {
+                    sleep(interval.mul_f64(0.1 * random_fraction())).await;
+                } else {
+                    sleep(jittered(interval)).await;
+                }
+                loop {
+                    let item = match request(self).await {
+                        Ok(APIResponse::Success(value)) => {
+                            diff(&mut state, value).map(|value| Ok(APIResponse::Success(value)))
+                        }
+                        other => Some(other),
+                    };
+                    if let Some(item) = item {
+                        return Some((item, (false, state, request, diff)));
+                    }
+                    sleep(jittered(interval)).await;
+                }
+            },
+        )
+    }
+
+    /// 轮询接口，`key` 变化时返回响应
+    pub fn watch<'a, T, K, F, Fut>(
+        &'a self,
+        interval: Duration,
+        request: F,
+        key: impl Fn(&T) -> K + 'a,
+    ) -> impl Stream<Item = APIResult<T>> + 'a
+    where
+        T: 'a,
+        K: PartialEq + 'a,
+        F: Fn(&'a QWeatherClient) -> Fut + 'a,
+        Fut: Future<Output = APIResult<T>> + 'a,
+    {
+        self.poll(
+            interval,
+            None,
+            request,
+            move |last: &mut Option<K>, value| {
+                let key = key(&value);
+                if last.as_ref() == Some(&key) {
+                    return None;
+                }
+                *last = Some(key);
+                Some(value)
+            },
+        )
+    }
+
+    /// 订阅实时天气，`update_time` 变化时返回，参见 [`QWeatherClient::weather_now`]
+    pub fn watch_weather_now<'a>(
+        &'a self,
+        location: &'a str,
+        interval: Duration,
+    ) -> impl Stream<Item = APIResult<WeatherNowResponse>> + 'a {
+        self.watch(
+            interval,
+            move |client| client.weather_now(location),
+            |resp: &WeatherNowResponse| resp.update_time,
+        )
+    }
+
+    /// 订阅分钟级降水，`update_time` 变化时返回，参见 [`QWeatherClient::minutely_precipitation`]
+    pub fn watch_minutely_precipitation<'a>(
+        &'a self,
+        location: &'a str,
+        interval: Duration,
+    ) -> impl Stream<Item = APIResult<MinutePrecipitationResponse>> + 'a {
+        self.watch(
+            interval,
+            move |client| client.minutely_precipitation(location),
+            |resp: &MinutePrecipitationResponse| resp.update_time,
+        )
+    }
+
+    /// 订阅实时空气质量，数据标签 `metadata.tag` 变化时返回，参见 [`QWeatherClient::air_current`]
+    pub fn watch_air_current(
+        &self,
+        latitude: f64,
+        longitude: f64,
+        interval: Duration,
+    ) -> impl Stream<Item = APIResult<AirCurrentResponse>> + '_ {
+        self.watch(
+            interval,
+            move |client| client.air_current(latitude, longitude),
+            |resp: &AirCurrentResponse| resp.metadata.tag.clone(),
+        )
+    }
+
+    /// 订阅天气灾害预警
+    ///
+    /// 第一次返回当前所有预警，之后只在出现新的预警或预警内容更新时返回，`warning` 中只包含新的和更新的预警。
+    /// 参见 [`QWeatherClient::weather_warning`]
+    pub fn watch_weather_warning<'a>(
+        &'a self,
+        location: &'a str,
+        interval: Duration,
+    ) -> impl Stream<Item = APIResult<WeatherWarningResponse>> + 'a {
+        self.poll(
+            interval,
+            None,
+            move |client| client.weather_warning(location),
+            |seen: &mut Option<HashMap<String, DateTime<FixedOffset>>>,
+             mut resp: WeatherWarningResponse| {
+                let current = resp
+                    .warning
+                    .iter()
+                    .map(|warning| (warning.id.clone(), warning.pub_time))
+                    .collect();
+                let Some(seen) = seen.replace(current) else {
+                    return Some(resp);
+                };
+                resp.warning
+                    .retain(|warning| seen.get(&warning.id) != Some(&warning.pub_time));
+
+                (!resp.warning.is_empty()).then_some(resp)
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+#[tokio::test(start_paused = true)]
+async fn test_watch() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use futures_util::StreamExt;
+    use tokio::time::Instant;
+
+    use crate::{
+        client::ClientConfig,
+        transport::{MockTransport, Response},
+    };
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let transport = {
+        let calls = calls.clone();
+        MockTransport::with_handler(move |request| {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+            let body = if request.url.ends_with("/v7/weather/now") {
+                // 每请求三次更新一次，第五次请求失败
+                if call == 4 {
+                    serde_json::json!({ "code": "500" })
+                } else {
+                    serde_json::json!({
+                      "code": "200",
+                      "updateTime": format!("2020-06-30T2{}:00+08:00", call / 3),
+                      "fxLink": "http://hfx.link/2ax1",
+                      "now": {
+                        "obsTime": "2020-06-30T21:40+08:00",
+                        "temp": "24",
+                        "feelsLike": "26",
+                        "icon": "101",
+                        "text": "多云",
+                        "wind360": "123",
+                        "windDir": "东南风",
+                        "windScale": "1",
+                        "windSpeed": "3",
+                        "humidity": "72",
+                        "precip": "0.0",
+                        "pressure": "1003",
+                        "vis": "16",
+                        "cloud": "10",
+                        "dew": "21"
+                      },
+                      "refer": { "sources": [], "license": [] }
+                    })
+                }
+            } else {
+                let warning = |id: &str, pub_time: &str| {
+                    serde_json::json!({
+                      "id": id,
+                      "sender": "",
+                      "pubTime": pub_time,
+                      "title": id,
+                      "startTime": "",
+                      "endTime": "",
+                      "status": "active",
+                      "severity": "Minor",
+                      "severityColor": "Blue",
+                      "type": "1001",
+                      "typeName": "台风",
+                      "urgency": "",
+                      "certainty": "",
+                      "text": "",
+                      "related": ""
+                    })
+                };
+                let warnings = match call {
+                    0 | 1 => vec![warning("a", "2023-04-03T10:30+08:00")],
+                    2 => vec![
+                        warning("a", "2023-04-03T10:30+08:00"),
+                        warning("b", "2023-04-03T10:30+08:00"),
+                    ],
+                    3 => vec![warning("b", "2023-04-03T10:30+08:00")],
+                    _ => vec![warning("b", "2023-04-03T11:30+08:00")],
+                };
+                serde_json::json!({
+                  "code": "200",
+                  "updateTime": "2023-04-03T10:30+08:00",
+                  "fxLink": "",
+                  "warning": warnings,
+                  "refer": { "sources": [], "license": [] }
+                })
+            };
+            Ok(Response::json(&body))
+        })
+    };
+    let client = QWeatherClient::with_transport(ClientConfig::new("id", "key"), transport);
+    let interval = Duration::from_secs(60);
+
+    let start = Instant::now();
+    let updates: Vec<_> = client
+        .watch_weather_now("101010100", interval)
+        .take(4)
+        .collect()
+        .await;
+    let update_time = |update: &APIResult<WeatherNowResponse>| match update {
+        Ok(APIResponse::Success(resp)) => resp.update_time.to_rfc3339(),
+        Ok(APIResponse::Error(code)) => code.clone(),
+        Ok(APIResponse::ParseError { error, .. }) => error.clone(),
+        Err(e) => e.to_string(),
+    };
+    let updates: Vec<_> = updates.iter().map(update_time).collect();
+    assert_eq!(
+        updates,
+        [
+            "2020-06-30T20:00:00+08:00",
+            "2020-06-30T21:00:00+08:00",
+            "500",
+            "2020-06-30T22:00:00+08:00"
+        ]
+    );
+    // 第 1、4、5、7 次请求有返回
+    assert_eq!(calls.load(Ordering::SeqCst), 7);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= interval * 6 && elapsed <= interval.mul_f64(6.7));
+
+    calls.store(0, Ordering::SeqCst);
+    let updates: Vec<_> = client
+        .watch_weather_warning("101010100", interval)
+        .take(3)
+        .collect()
+        .await;
+    let ids: Vec<_> = updates
+        .iter()
+        .map(|update| match update {
+            Ok(APIResponse::Success(resp)) => resp
+                .warning
+                .iter()
+                .map(|warning| warning.id.as_str())
+                .collect::<Vec<_>>(),
+            other => panic!("unexpected {:?}", other),
+        })
+        .collect();
+    assert_eq!(ids, [["a"], ["b"], ["b"]]);
+    assert_eq!(calls.load(Ordering::SeqCst), 5);
+}
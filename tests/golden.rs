@@ -0,0 +1,130 @@
+//! 解析 `tests/fixtures` 中的所有响应样例，检查序列化后能重新解析为相同的结果，并与 `tests/snapshots`
+//! 中保存的结果比较
+//!
+//! 修改数据结构或样例后，使用 `UPDATE_GOLDEN=1 cargo test --test golden` 重新生成快照。
+
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use qweather_sdk::{
+    api::{
+        air_quality::{
+            AirCurrentResponse, AirDailyForecastResponse, AirHourlyForecastResponse,
+            AirStationResponse,
+        },
+        geo::{CityLookupResponse, POIResponse, TopCityResponse},
+        grid_weather::{
+            GridWeatherDailyForecastResponse, GridWeatherHourlyForecastResponse,
+            GridWeatherNowResponse,
+        },
+        indices::IndicesForecastResponse,
+        minutely::MinutePrecipitationResponse,
+        tropical_cyclone::StormForecastResponse,
+        warning::{WeatherWarningCityListResponse, WeatherWarningResponse},
+        weather::{
+            WeatherDailyForecastResponse, WeatherHourlyForecastResponse, WeatherNowResponse,
+        },
+        APIResponse, RawResponse,
+    },
+    fixtures::assert_round_trip,
+};
+
+/// 按接口对应的数据结构解析响应体并检查序列化往返，返回格式化后的结果
+fn decode(endpoint: &str, body: serde_json::Value) -> String {
+    let raw = RawResponse {
+        status: 200,
+        headers: BTreeMap::new(),
+        body,
+    };
+
+    macro_rules! decode {
+        ($($endpoint:literal => $ty:ty),* $(,)?) => {
+            match endpoint {
+                $($endpoint => {
+                    let resp = raw.decode::<$ty>();
+                    match &resp {
+                        APIResponse::Success(resp) => assert_round_trip(resp),
+                        APIResponse::ParseError { error, .. } => {
+                            panic!("failed to parse {}: {}", endpoint, error)
+                        }
+                        APIResponse::Error(_) => {}
+                    }
+                    format!("{:#?}\n", resp)
+                })*
+                _ => panic!("unknown endpoint {}, add it to tests/golden.rs", endpoint),
+            }
+        };
+    }
+
+    decode! {
+        "air_current" => AirCurrentResponse,
+        "air_daily_forecast" => AirDailyForecastResponse,
+        "air_hourly_forecast" => AirHourlyForecastResponse,
+        "air_station" => AirStationResponse,
+        "geo_city_lookup" => CityLookupResponse,
+        "geo_city_top" => TopCityResponse,
+        "geo_poi_lookup" => POIResponse,
+        "geo_poi_range" => POIResponse,
+        "grid_weather_daily_forecast" => GridWeatherDailyForecastResponse,
+        "grid_weather_hourly_forecast" => GridWeatherHourlyForecastResponse,
+        "grid_weather_now" => GridWeatherNowResponse,
+        "indices_forecast" => IndicesForecastResponse,
+        "minutely_precipitation" => MinutePrecipitationResponse,
+        "storm_forecast" => StormForecastResponse,
+        "weather_daily_forecast" => WeatherDailyForecastResponse,
+        "weather_hourly_forecast" => WeatherHourlyForecastResponse,
+        "weather_now" => WeatherNowResponse,
+        "weather_warning" => WeatherWarningResponse,
+        "weather_warning_city_list" => WeatherWarningCityListResponse,
+    }
+}
+
+fn fixtures(root: &Path) -> Vec<PathBuf> {
+    let mut files = vec![];
+    for dir in fs::read_dir(root).unwrap() {
+        for file in fs::read_dir(dir.unwrap().path()).unwrap() {
+            let path = file.unwrap().path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    files
+}
+
+#[test]
+fn golden() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+
+    let mut failures = vec![];
+    for fixture in fixtures(&root.join("fixtures")) {
+        let relative = fixture.strip_prefix(root.join("fixtures")).unwrap();
+        let endpoint = relative.parent().unwrap().to_str().unwrap();
+        let body = serde_json::from_str(&fs::read_to_string(&fixture).unwrap())
+            .unwrap_or_else(|e| panic!("invalid JSON in {}: {}", relative.display(), e));
+        let actual = decode(endpoint, body);
+
+        let snapshot = root.join("snapshots").join(relative.with_extension("snap"));
+        if update {
+            fs::create_dir_all(snapshot.parent().unwrap()).unwrap();
+            fs::write(&snapshot, actual).unwrap();
+            continue;
+        }
+        match fs::read_to_string(&snapshot) {
+            Ok(expected) if expected == actual => {}
+            Ok(_) => failures.push(format!("{} does not match", relative.display())),
+            Err(_) => failures.push(format!("{} has no snapshot", relative.display())),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\nrun `UPDATE_GOLDEN=1 cargo test --test golden` to update snapshots",
+        failures.join("\n")
+    );
+}